@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+use bevy_camera_experiment::{
+    prelude::*,
+    scene::spawn_environment,
+};
+
+fn main() {
+    App::new()
+    .add_plugins(DefaultPlugins)
+    .add_plugins(OrbitCameraPlugin)
+    .add_systems(Startup, (spawn_environment, setup))
+    .run();
+}
+
+fn setup(mut commands: Commands) {
+    OrbitCameraBuilder::new(Vec3::new(0., 0.5, 0.))
+        .with_mode(CameraMode::FirstPerson)
+        .with_pitch(90f32.to_radians())
+        .with_pitch_limits(10f32.to_radians(), 170f32.to_radians())
+        .with_sensitivity(0.0025, 1.5)
+        .spawn(&mut commands);
+}
@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+use bevy_camera_experiment::{
+    prelude::*,
+    scene::spawn_environment,
+};
+
+fn main() {
+    App::new()
+    .add_plugins(DefaultPlugins)
+    .add_plugins(OrbitCameraPlugin)
+    .add_systems(Startup, (spawn_environment, setup))
+    .run();
+}
+
+fn setup(mut commands: Commands) {
+    let north = commands
+        .spawn((FixedCamera, Transform::from_xyz(0., 4., 6.).looking_at(Vec3::new(0., 0., -1.), Vec3::Y)))
+        .id();
+    let south = commands
+        .spawn((FixedCamera, Transform::from_xyz(4., 3., -6.).looking_at(Vec3::new(0., 0., 1.), Vec3::Y)))
+        .id();
+
+    commands.spawn((
+        CameraVolume { half_extents: Vec3::new(2.5, 2., 1.25), camera: north },
+        Transform::from_xyz(0., 1., -1.25),
+    ));
+    commands.spawn((
+        CameraVolume { half_extents: Vec3::new(2.5, 2., 1.25), camera: south },
+        Transform::from_xyz(0., 1., 1.25),
+    ));
+
+    OrbitCameraBuilder::new(Vec3::new(0., 0.5, 0.))
+        .with_mode(CameraMode::Fixed)
+        .spawn(&mut commands);
+}
@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+use bevy_camera_experiment::{
+    prelude::*,
+    scene::spawn_environment,
+};
+
+#[derive(Component)]
+struct Aircraft;
+
+fn main() {
+    App::new()
+    .add_plugins(DefaultPlugins)
+    .add_plugins(OrbitCameraPlugin)
+    .add_systems(Startup, (spawn_environment, setup))
+    .add_systems(Update, fly.before(CameraSet::Rig))
+    .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>
+) {
+    let aircraft = commands
+        .spawn((
+            Aircraft,
+            Mesh3d(meshes.add(Cuboid::new(3., 0.2, 1.))),
+            MeshMaterial3d(materials.add(Color::srgb(0.3, 0.4, 0.8))),
+            Transform::from_xyz(0., 5., 0.),
+        ))
+        .id();
+
+    let pivot = OrbitCameraBuilder::new(Vec3::ZERO)
+        .with_mode(CameraMode::Flight)
+        .with_roll(RollSource::Target, false)
+        .spawn(&mut commands);
+    commands.entity(pivot).insert(FlightCamera::new(aircraft));
+}
+
+fn fly(
+    time: Res<Time>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut query: Query<&mut Transform, With<Aircraft>>,
+) {
+    let dt = time.delta_secs();
+    for mut transform in query.iter_mut() {
+        let mut pitch = 0.;
+        let mut roll = 0.;
+        if keyboard_input.pressed(KeyCode::ArrowUp) { pitch -= 1.; }
+        if keyboard_input.pressed(KeyCode::ArrowDown) { pitch += 1.; }
+        if keyboard_input.pressed(KeyCode::ArrowLeft) { roll += 1.; }
+        if keyboard_input.pressed(KeyCode::ArrowRight) { roll -= 1.; }
+
+        transform.rotate_local_x(pitch * dt);
+        transform.rotate_local_z(roll * 2. * dt);
+
+        let forward = *transform.forward();
+        transform.translation += forward * 12. * dt;
+    }
+}
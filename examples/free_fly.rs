@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+use bevy_camera_experiment::{
+    prelude::*,
+    scene::spawn_environment,
+};
+
+fn main() {
+    App::new()
+    .add_plugins(DefaultPlugins)
+    .add_plugins(OrbitCameraPlugin)
+    .insert_resource(CameraSettings {
+        fly_speed: 25.,
+        ..default()
+    })
+    .add_systems(Startup, (spawn_environment, setup))
+    .run();
+}
+
+fn setup(mut commands: Commands) {
+    OrbitCameraBuilder::new(Vec3::new(0., 0.5, 0.))
+        .with_mode(CameraMode::FreeFly)
+        .with_pitch(60f32.to_radians())
+        .with_distance(15.)
+        .spawn(&mut commands);
+}
@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+use bevy_camera_experiment::prelude::*;
+
+fn main() {
+    App::new()
+    .add_plugins(DefaultPlugins)
+    .add_plugins(OrbitCameraPlugin)
+    .add_systems(Startup, setup)
+    .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>
+) {
+    let model = commands
+        .spawn((
+            Mesh3d(meshes.add(Torus::new(1., 2.))),
+            MeshMaterial3d(materials.add(Color::srgb(0.8, 0.7, 0.6))),
+            Transform::from_xyz(0., 1., 0.),
+        ))
+        .id();
+
+    commands.spawn((
+        DirectionalLight::default(),
+        Transform::from_xyz(3., 5., 2.).looking_at(Vec3::ZERO, Vec3::Y),
+    ));
+
+    let pivot = OrbitCameraBuilder::new(Vec3::new(0., 1., 0.))
+        .with_mode(CameraMode::Turntable)
+        .with_distance(8.)
+        .spawn(&mut commands);
+    commands.entity(pivot).insert((
+        Turntable {
+            focus: Vec3::new(0., 1., 0.),
+            focus_entity: Some(model),
+            distance: 8.,
+            ..default()
+        },
+        OrbitInertia::default(),
+    ));
+}
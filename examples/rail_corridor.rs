@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+use bevy_camera_experiment::{
+    prelude::*,
+    scene::spawn_environment,
+};
+
+fn main() {
+    App::new()
+    .add_plugins(DefaultPlugins)
+    .add_plugins(OrbitCameraPlugin)
+    .add_systems(Startup, (spawn_environment, setup))
+    .run();
+}
+
+fn setup(mut commands: Commands) {
+    let rail = RailCamera::through([
+        Vec3::new(-8., 3., 6.),
+        Vec3::new(-4., 3., 4.),
+        Vec3::new(0., 4., 5.),
+        Vec3::new(4., 3., 4.),
+        Vec3::new(8., 3., 6.),
+    ])
+    .expect("rail needs at least two points");
+
+    let pivot = OrbitCameraBuilder::new(Vec3::new(0., 0.5, 0.))
+        .with_mode(CameraMode::Rail)
+        .spawn(&mut commands);
+    commands.entity(pivot).insert(rail);
+}
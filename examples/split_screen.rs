@@ -0,0 +1,64 @@
+use bevy::prelude::*;
+use bevy_camera_experiment::{
+    prelude::*,
+    scene::spawn_environment,
+};
+
+/// The rig the first gamepad to connect takes over.
+#[derive(Component)]
+struct SecondSeat;
+
+fn main() {
+    App::new()
+    .add_plugins(DefaultPlugins)
+    .add_plugins(OrbitCameraPlugin)
+    .add_systems(Startup, (spawn_environment, setup).chain())
+    .add_systems(Update, claim_gamepad)
+    .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut players: Query<&mut Player>,
+) {
+    let first = OrbitCameraBuilder::new(Vec3::new(0., 0.5, 0.))
+        .with_distance(8.)
+        .with_input_source(InputSource::KeyboardMouse)
+        .with_screen_slot(0)
+        .spawn(&mut commands);
+    for mut player in players.iter_mut() {
+        player.rig = Some(first);
+    }
+
+    // Bound to no gamepad until one connects, so the keyboard only drives the first seat.
+    let second = OrbitCameraBuilder::new(Vec3::new(3., 0.5, 0.))
+        .with_distance(8.)
+        .with_input_source(InputSource::Gamepad(Entity::PLACEHOLDER))
+        .with_screen_slot(1)
+        .spawn(&mut commands);
+    commands.entity(second).insert(SecondSeat);
+
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::from_length(1.))),
+        MeshMaterial3d(materials.add(Color::srgb(0.2, 0.4, 1.0))),
+        Transform::from_xyz(3., 0.5, 0.),
+        Player {
+            rig: Some(second),
+            ..default()
+        },
+    ));
+}
+
+fn claim_gamepad(
+    gamepads: Query<Entity, Added<Gamepad>>,
+    mut seats: Query<&mut InputSource, With<SecondSeat>>,
+) {
+    let Some(gamepad) = gamepads.iter().next() else { return };
+    for mut source in seats.iter_mut() {
+        if *source == InputSource::Gamepad(Entity::PLACEHOLDER) {
+            *source = InputSource::Gamepad(gamepad);
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use bevy::{
+    prelude::*,
+    diagnostic::FrameTimeDiagnosticsPlugin,
+};
+use bevy_camera_experiment::{
+    prelude::*,
+    scene::spawn_environment,
+};
+
+fn main() {
+    App::new()
+    .add_plugins((DefaultPlugins, FrameTimeDiagnosticsPlugin))
+    .add_plugins(OrbitCameraPlugin)
+    .add_systems(Startup, (spawn_environment, setup))
+    .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>
+) {
+    let pivot = OrbitCameraBuilder::new(Vec3::new(0., 0.5, 0.))
+        .with_shoulder_offset(Vec2::new(1.5, 0.5))
+        .spawn(&mut commands);
+    commands
+        .entity(pivot)
+        .insert((
+            Mesh3d(meshes.add(Cuboid::from_length(0.1))),
+            MeshMaterial3d(materials.add(Color::srgb(1.0, 1.0, 1.0))),
+            CameraFollow::default(),
+        ));
+
+    #[cfg(feature = "collision")]
+    commands.entity(pivot).insert((Whiskers::default(), OcclusionAssist::default()));
+}
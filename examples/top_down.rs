@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use bevy_camera_experiment::{
+    prelude::*,
+    scene::spawn_environment,
+};
+
+fn main() {
+    App::new()
+    .add_plugins(DefaultPlugins)
+    .add_plugins(OrbitCameraPlugin)
+    .add_systems(Startup, (spawn_environment, setup))
+    .run();
+}
+
+fn setup(mut commands: Commands) {
+    OrbitCameraBuilder::new(Vec3::ZERO)
+        .with_mode(CameraMode::TopDown)
+        .with_distance(25.)
+        .spawn(&mut commands);
+}
@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+use bevy_camera_experiment::{
+    prelude::*,
+    scene::spawn_environment,
+};
+
+fn main() {
+    App::new()
+    .add_plugins(DefaultPlugins)
+    .add_plugins(OrbitCameraPlugin)
+    .add_systems(Startup, (spawn_environment, setup))
+    .run();
+}
+
+fn setup(mut commands: Commands) {
+    OrbitCameraBuilder::new(Vec3::new(0., 0.5, 0.))
+        .with_mode(CameraMode::TwinStick)
+        .spawn(&mut commands);
+}
@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+use bevy_camera_experiment::{
+    prelude::*,
+    scene::spawn_environment,
+};
+
+#[derive(Component)]
+struct Vehicle {
+    speed: f32,
+}
+
+fn main() {
+    App::new()
+    .add_plugins(DefaultPlugins)
+    .add_plugins(OrbitCameraPlugin)
+    .add_systems(Startup, (spawn_environment, setup))
+    .add_systems(Update, drive.before(CameraSet::Rig))
+    .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>
+) {
+    let vehicle = commands
+        .spawn((
+            Vehicle { speed: 0. },
+            Mesh3d(meshes.add(Cuboid::new(1., 0.5, 2.))),
+            MeshMaterial3d(materials.add(Color::srgb(0.8, 0.2, 0.2))),
+            Transform::from_xyz(0., 0.25, 0.),
+        ))
+        .id();
+
+    let pivot = OrbitCameraBuilder::new(Vec3::ZERO)
+        .with_mode(CameraMode::Chase)
+        .spawn(&mut commands);
+    commands.entity(pivot).insert(ChaseCamera::new(vehicle));
+}
+
+fn drive(
+    time: Res<Time>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut query: Query<(&mut Vehicle, &mut Transform)>,
+) {
+    let dt = time.delta_secs();
+    for (mut vehicle, mut transform) in query.iter_mut() {
+        if keyboard_input.pressed(KeyCode::ArrowUp) { vehicle.speed += 20. * dt; }
+        if keyboard_input.pressed(KeyCode::ArrowDown) { vehicle.speed -= 20. * dt; }
+        vehicle.speed = vehicle.speed.clamp(-10., 40.);
+
+        let mut steer = 0.;
+        if keyboard_input.pressed(KeyCode::ArrowLeft) { steer += 1.; }
+        if keyboard_input.pressed(KeyCode::ArrowRight) { steer -= 1.; }
+        transform.rotate_y(steer * 1.5 * dt);
+
+        let forward = *transform.forward();
+        transform.translation += forward * vehicle.speed * dt;
+    }
+}
@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+use bevy_camera_experiment::{
+    prelude::*,
+    scene::spawn_environment,
+};
+
+#[derive(Component)]
+struct Closeup;
+
+fn main() {
+    App::new()
+    .add_plugins(DefaultPlugins)
+    .add_plugins(OrbitCameraPlugin)
+    .add_systems(Startup, (spawn_environment, setup).chain())
+    .add_systems(Update, toggle_closeup)
+    .run();
+}
+
+fn setup(mut commands: Commands, players: Query<Entity, With<Player>>) {
+    commands.spawn(CameraBrain::default());
+
+    let player = players.get_single().ok();
+
+    commands.spawn((
+        VirtualCamera {
+            priority: 10,
+            look_at: player,
+            ..default()
+        },
+        Transform::from_xyz(-6., 6., 8.),
+    ));
+    commands.spawn((
+        Closeup,
+        VirtualCamera {
+            priority: 0,
+            follow: player,
+            look_at: player,
+            offset: Vec3::new(1.5, 1., 2.5),
+            fov: Some(30f32.to_radians()),
+            damping: 0.2,
+            ..default()
+        },
+    ));
+}
+
+fn toggle_closeup(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut query: Query<&mut VirtualCamera, With<Closeup>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Space) {
+        for mut vcam in query.iter_mut() {
+            vcam.priority = if vcam.priority > 10 { 0 } else { 20 };
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use bevy::prelude::*;
+
+use crate::{
+    easing::smoothstep,
+    mode::{
+        CameraMode,
+        CameraModeState,
+    },
+    rig::CameraRig,
+};
+
+/// Pulls the camera in over the shoulder and narrows the lens while the rig is aiming.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct AimDownSights {
+    /// Camera distance from the pivot while fully aimed.
+    pub distance: f32,
+    /// Extra shoulder offset while fully aimed, right and up of the pivot.
+    pub shoulder_offset: Vec2,
+    pub fov: f32,
+    /// Time taken to blend fully in or out, in seconds.
+    pub blend_time: f32,
+    /// Show the `Crosshair` while aimed.
+    pub crosshair: bool,
+
+    /// How far aimed the camera is, 0 to 1.
+    pub weight: f32,
+    /// Field of view restored once the camera leaves the sights.
+    pub base_fov: Option<f32>,
+}
+
+impl Default for AimDownSights {
+    fn default() -> Self {
+        Self {
+            distance: 2.5,
+            shoulder_offset: Vec2::new(0.6, 0.2),
+            fov: 35f32.to_radians(),
+            blend_time: 0.15,
+            crosshair: true,
+
+            weight: 0.,
+            base_fov: None,
+        }
+    }
+}
+
+/// Centre-screen marker shown while an `AimDownSights` rig with `crosshair` is aimed; it only
+/// exists while such a rig does.
+#[derive(Component)]
+pub struct Crosshair;
+
+pub fn aim_down_sights(
+    time: Res<Time>,
+    mut rigs: Query<(&CameraRig, &mut AimDownSights, &mut CameraModeState)>,
+    mut projections: Query<&mut Projection>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for (rig, mut ads, mut state) in rigs.iter_mut() {
+        let aiming = rig.aiming && rig.mode.follows_pivot();
+        let step = if ads.blend_time > 0. { delta_seconds / ads.blend_time } else { 1. };
+        ads.weight = (ads.weight + if aiming { step } else { -step }).clamp(0., 1.);
+
+        if let Some(mut projection) = rig.camera_entity.and_then(|camera| projections.get_mut(camera).ok()) {
+            if let Projection::Perspective(perspective) = projection.as_mut() {
+                if ads.weight > 0. {
+                    let base = *ads.base_fov.get_or_insert(perspective.fov);
+                    perspective.fov = base.lerp(ads.fov, smoothstep(ads.weight));
+                } else if let Some(base) = ads.base_fov.take() {
+                    perspective.fov = base;
+                }
+            }
+        }
+
+        if ads.weight == 0. || rig.mode == CameraMode::FirstPerson {
+            continue;
+        }
+
+        let t = smoothstep(ads.weight);
+        let side = if rig.left_shoulder { -1. } else { 1. };
+        let pose = &mut state.desired;
+        let closer = (rig.camera_distance - ads.distance).max(0.) * t;
+        let shoulder = *pose.right() * ads.shoulder_offset.x * side + Vec3::Y * ads.shoulder_offset.y;
+        pose.translation += *pose.forward() * closer + shoulder * t;
+    }
+}
+
+pub fn update_crosshair(
+    mut commands: Commands,
+    rigs: Query<&AimDownSights>,
+    mut crosshairs: Query<(Entity, &mut Visibility, &mut BackgroundColor), With<Crosshair>>,
+) {
+    let Some(weight) = rigs.iter().filter(|ads| ads.crosshair).map(|ads| ads.weight).reduce(f32::max) else {
+        for (entity, ..) in crosshairs.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    };
+
+    if crosshairs.is_empty() {
+        commands.spawn((
+            Crosshair,
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(50.),
+                top: Val::Percent(50.),
+                width: Val::Px(4.),
+                height: Val::Px(4.),
+                margin: UiRect::new(Val::Px(-2.), Val::ZERO, Val::Px(-2.), Val::ZERO),
+                ..default()
+            },
+            BackgroundColor(Color::WHITE),
+            Visibility::Hidden,
+        ));
+        return;
+    }
+
+    for (_, mut visibility, mut color) in crosshairs.iter_mut() {
+        *visibility = if weight > 0. { Visibility::Inherited } else { Visibility::Hidden };
+        color.0.set_alpha(smoothstep(weight));
+    }
+}
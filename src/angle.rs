@@ -0,0 +1,82 @@
+use std::f32::consts::{
+    PI,
+    TAU,
+};
+
+/// Wraps `angle` into `[-π, π)`.
+pub fn wrap_angle(angle: f32) -> f32 {
+    let wrapped = (angle + PI).rem_euclid(TAU) - PI;
+    // `rem_euclid` can round up to exactly `TAU` for tiny negative inputs.
+    if wrapped >= PI { wrapped - TAU } else { wrapped }
+}
+
+/// Signed shortest turn from `from` to `to`, in `[-π, π)`.
+pub fn angle_delta(from: f32, to: f32) -> f32 {
+    wrap_angle(to - from)
+}
+
+/// Interpolates from `from` to `to` the short way round. The result is not wrapped.
+pub fn lerp_angle(from: f32, to: f32, t: f32) -> f32 {
+    from + angle_delta(from, to) * t
+}
+
+/// Turns `from` towards `to` the short way round by at most `max_step`.
+pub fn move_towards_angle(from: f32, to: f32, max_step: f32) -> f32 {
+    from + angle_delta(from, to).clamp(-max_step, max_step)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f32 = 1e-5;
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < EPSILON, "{a} != {b}");
+    }
+
+    #[test]
+    fn wrap_keeps_range() {
+        for i in -200..=200 {
+            let angle = i as f32 * 0.37;
+            let wrapped = wrap_angle(angle);
+            assert!((-PI..PI).contains(&wrapped), "{angle} wrapped to {wrapped}");
+            assert!(wrap_angle(wrapped - angle).abs() < 1e-3, "{angle} wrapped to {wrapped}");
+        }
+    }
+
+    #[test]
+    fn wrap_boundaries() {
+        assert_close(wrap_angle(0.), 0.);
+        assert_close(wrap_angle(PI), -PI);
+        assert_close(wrap_angle(-PI), -PI);
+        assert_close(wrap_angle(TAU), 0.);
+        assert_close(wrap_angle(3. * PI / 2.), -PI / 2.);
+        assert!(wrap_angle(-1e-9) < PI);
+    }
+
+    #[test]
+    fn delta_takes_short_way() {
+        assert_close(angle_delta(0.1, -0.1), -0.2);
+        assert_close(angle_delta(PI - 0.1, -PI + 0.1), 0.2);
+        assert_close(angle_delta(-PI + 0.1, PI - 0.1), -0.2);
+        assert_close(angle_delta(10. * TAU, 0.5), 0.5);
+    }
+
+    #[test]
+    fn lerp_crosses_seam() {
+        let from = PI - 0.2;
+        let to = -PI + 0.2;
+        assert_close(lerp_angle(from, to, 0.), from);
+        assert_close(wrap_angle(lerp_angle(from, to, 1.)), to);
+        assert_close(wrap_angle(lerp_angle(from, to, 0.5)), -PI);
+    }
+
+    #[test]
+    fn move_towards_clamps_step() {
+        assert_close(move_towards_angle(0., 1., 0.25), 0.25);
+        assert_close(move_towards_angle(0., -1., 0.25), -0.25);
+        assert_close(move_towards_angle(0., 0.1, 0.25), 0.1);
+        assert_close(move_towards_angle(PI - 0.1, -PI + 0.1, 0.5), PI + 0.1);
+    }
+}
@@ -0,0 +1,94 @@
+use bevy::prelude::*;
+
+use crate::{
+    angle::lerp_angle,
+    easing::smoothstep,
+    input::MouseDeltas,
+    input_map::{
+        ActionInput,
+        CameraAction,
+    },
+    mode::CameraMode,
+    rig::CameraRig,
+};
+#[cfg(feature = "gamepad")]
+use crate::gamepad::GamepadAxes;
+
+/// Something worth looking at; the camera drifts towards it while the pivot is within `radius`.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct PointOfInterest {
+    pub radius: f32,
+    /// Turn rate towards the point when the pivot stands on it, per second; it fades to nothing at `radius`.
+    pub strength: f32,
+    /// Share of the pull applied to pitch, so the view mostly turns rather than tilts.
+    pub pitch_share: f32,
+}
+
+impl Default for PointOfInterest {
+    fn default() -> Self {
+        Self {
+            radius: 8.,
+            strength: 1.5,
+            pitch_share: 0.5,
+        }
+    }
+}
+
+pub fn attend_points_of_interest(
+    time: Res<Time>,
+    actions: ActionInput,
+    deltas: Res<MouseDeltas>,
+    #[cfg(feature = "gamepad")]
+    gamepad: Res<GamepadAxes>,
+    points: Query<(&PointOfInterest, &GlobalTransform)>,
+    mut rigs: Query<(&mut CameraRig, &Transform)>,
+) {
+    #[cfg(feature = "gamepad")]
+    let stick = gamepad.look != Vec2::ZERO;
+    #[cfg(not(feature = "gamepad"))]
+    let stick = false;
+
+    let looking = stick
+        || deltas.motion != Vec2::ZERO
+        || actions.pressed(CameraAction::OrbitLeft)
+        || actions.pressed(CameraAction::OrbitRight)
+        || actions.pressed(CameraAction::OrbitUp)
+        || actions.pressed(CameraAction::OrbitDown);
+    if looking {
+        return;
+    }
+
+    let delta_seconds = time.delta_secs();
+
+    for (mut rig, pivot) in rigs.iter_mut() {
+        if !matches!(rig.mode, CameraMode::Orbit | CameraMode::FirstPerson) {
+            continue;
+        }
+
+        // The point pulling hardest wins, rather than averaging directions.
+        let strongest = points
+            .iter()
+            .filter_map(|(point, transform)| {
+                let offset = transform.translation() - pivot.translation;
+                let distance = offset.length();
+                if point.radius <= 0. || distance >= point.radius || distance < 1e-3 {
+                    return None;
+                }
+                let pull = point.strength * smoothstep(1. - distance / point.radius);
+                Some((offset, pull, point.pitch_share))
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        let Some((offset, pull, pitch_share)) = strongest else {
+            continue;
+        };
+
+        let blend = 1. - (-pull * delta_seconds).exp();
+        rig.yaw = lerp_angle(rig.yaw, CameraRig::yaw_towards(offset), blend);
+
+        // Pitch is measured from straight down, so level is a quarter turn.
+        let elevation = offset.y.atan2(offset.with_y(0.).length());
+        let pitch = (std::f32::consts::FRAC_PI_2 + elevation).clamp(rig.min_pitch, rig.max_pitch);
+        let pitch_blend = 1. - (-pull * pitch_share * delta_seconds).exp();
+        rig.camera_pitch = rig.camera_pitch.lerp(pitch, pitch_blend);
+    }
+}
@@ -0,0 +1,147 @@
+use bevy::prelude::*;
+
+use crate::{
+    angle::lerp_angle,
+    focus::FocusTarget,
+    input_map::{
+        ActionInput,
+        BOOKMARK_ACTIONS,
+        CameraAction,
+    },
+    rig::CameraRig,
+    settings::CameraSettings,
+    split_screen::InputSource,
+};
+
+/// A stored orbit framing: angles, distance and the world point the camera orbits.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct CameraBookmark {
+    pub yaw: f32,
+    pub pitch: f32,
+    pub distance: f32,
+    pub focus: Vec3,
+}
+
+impl CameraBookmark {
+    pub fn capture(rig: &CameraRig, pivot: &Transform) -> Self {
+        Self {
+            yaw: rig.yaw,
+            pitch: rig.camera_pitch,
+            distance: rig.camera_distance,
+            focus: pivot.translation + rig.focus_offset,
+        }
+    }
+
+    /// Interpolates towards `other`, turning the short way round.
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self {
+            yaw: lerp_angle(self.yaw, other.yaw, t),
+            pitch: self.pitch.lerp(other.pitch, t),
+            distance: self.distance.lerp(other.distance, t),
+            focus: self.focus.lerp(other.focus, t),
+        }
+    }
+}
+
+/// Nine bookmark slots; store with `BookmarkModifier` plus a bookmark key, recall with the key alone.
+#[derive(Resource, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct CameraBookmarks {
+    pub slots: [Option<CameraBookmark>; 9],
+    /// File rewritten whenever a bookmark is stored.
+    #[cfg(feature = "serialize")]
+    #[serde(skip)]
+    pub autosave: Option<std::path::PathBuf>,
+}
+
+#[cfg(feature = "serialize")]
+impl CameraBookmarks {
+    pub fn to_ron(&self) -> Result<String, ron::Error> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+    }
+
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source)
+    }
+
+    /// Reads bookmarks saved at `path` and keeps saving back to it; starts empty if the file is missing or invalid.
+    pub fn load(path: impl Into<std::path::PathBuf>) -> Self {
+        let path = path.into();
+        let mut bookmarks = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|source| Self::from_ron(&source).ok())
+            .unwrap_or_default();
+        bookmarks.autosave = Some(path);
+        bookmarks
+    }
+
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        let source = self.to_ron().map_err(std::io::Error::other)?;
+        std::fs::write(path, source)
+    }
+}
+
+/// Eases a rig from one framing to a recalled bookmark.
+#[derive(Component)]
+pub struct BookmarkFlight {
+    pub from: CameraBookmark,
+    pub to: CameraBookmark,
+    pub elapsed: f32,
+}
+
+pub fn use_bookmarks(
+    mut commands: Commands,
+    actions: ActionInput,
+    mut bookmarks: ResMut<CameraBookmarks>,
+    rigs: Query<(Entity, &CameraRig, &Transform, Option<&InputSource>)>,
+) {
+    for (pivot, rig, transform, source) in rigs.iter() {
+        if !rig.mode.follows_pivot() {
+            continue;
+        }
+        let Some(slot) = BOOKMARK_ACTIONS.iter().position(|action| actions.just_pressed_by(*action, source)) else {
+            continue;
+        };
+
+        let current = CameraBookmark::capture(rig, transform);
+        if actions.pressed_by(CameraAction::BookmarkModifier, source) {
+            bookmarks.slots[slot] = Some(current);
+
+            #[cfg(feature = "serialize")]
+            if let Some(path) = &bookmarks.autosave {
+                if let Err(error) = bookmarks.save(path) {
+                    warn!("failed to save camera bookmarks to {}: {error}", path.display());
+                }
+            }
+        } else if let Some(bookmark) = bookmarks.slots[slot] {
+            commands.entity(pivot).remove::<FocusTarget>().insert(BookmarkFlight {
+                from: current,
+                to: bookmark,
+                elapsed: 0.,
+            });
+        }
+    }
+}
+
+pub fn fly_to_bookmarks(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<CameraSettings>,
+    mut rigs: Query<(Entity, &mut CameraRig, &Transform, &mut BookmarkFlight)>,
+) {
+    for (pivot, mut rig, transform, mut flight) in rigs.iter_mut() {
+        flight.elapsed += time.delta_secs();
+        let t = if settings.bookmark_duration > 0. { flight.elapsed / settings.bookmark_duration } else { 1. };
+        let framing = flight.from.lerp(&flight.to, settings.bookmark_easing.apply(t));
+
+        rig.yaw = framing.yaw;
+        rig.camera_pitch = framing.pitch;
+        rig.set_distance(framing.distance);
+        rig.focus_offset = framing.focus - transform.translation;
+
+        if t >= 1. {
+            commands.entity(pivot).remove::<BookmarkFlight>();
+        }
+    }
+}
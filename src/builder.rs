@@ -0,0 +1,252 @@
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+
+use crate::{
+    easing::Easing,
+    fixed::ActiveFixedCamera,
+    isometric::Isometric,
+    mode::{
+        CameraMode,
+        CameraModeState,
+    },
+    rear_view::RearView,
+    rig::{
+        CameraLens,
+        CameraRig,
+        RollSource,
+        ZoomMode,
+    },
+    side_scroller::SideScroller,
+    split_screen::{
+        InputSource,
+        ScreenSlot,
+    },
+    top_down::TopDown,
+    turntable::Turntable,
+    twin_stick::TwinStick,
+};
+
+pub struct OrbitCameraBuilder {
+    target: Vec3,
+    rig: CameraRig,
+    projection: Option<Projection>,
+    blend_duration: f32,
+    easing: Easing,
+    input_source: Option<InputSource>,
+    screen_slot: Option<ScreenSlot>,
+    rear_view: Option<RearView>,
+}
+
+impl OrbitCameraBuilder {
+    pub fn new(target: Vec3) -> Self {
+        Self {
+            target,
+            rig: CameraRig::default(),
+            projection: None,
+            blend_duration: CameraModeState::default().blend_duration,
+            easing: Easing::default(),
+            input_source: None,
+            screen_slot: None,
+            rear_view: None,
+        }
+    }
+
+    pub fn with_yaw(mut self, yaw: f32) -> Self {
+        self.rig.yaw = yaw;
+        self
+    }
+
+    pub fn with_pitch(mut self, pitch: f32) -> Self {
+        self.rig.camera_pitch = pitch;
+        self
+    }
+
+    pub fn with_distance(mut self, distance: f32) -> Self {
+        self.rig.set_distance(distance);
+        self
+    }
+
+    pub fn with_distance_limits(mut self, min: f32, max: f32) -> Self {
+        self.rig.min_distance = min;
+        self.rig.max_distance = max;
+        self
+    }
+
+    pub fn with_pitch_limits(mut self, min: f32, max: f32) -> Self {
+        self.rig.min_pitch = min;
+        self.rig.max_pitch = max;
+        self
+    }
+
+    pub fn with_zoom_mode(mut self, zoom_mode: ZoomMode) -> Self {
+        self.rig.zoom_mode = zoom_mode;
+        self
+    }
+
+    pub fn with_zoom_presets(mut self, presets: impl IntoIterator<Item = f32>) -> Self {
+        self.rig.zoom_presets = presets.into_iter().collect();
+        self.rig.zoom_presets.sort_by(f32::total_cmp);
+        self
+    }
+
+    pub fn with_soft_pitch_zone(mut self, zone: f32) -> Self {
+        self.rig.soft_pitch_zone = zone;
+        self
+    }
+
+    pub fn with_sensitivity(mut self, look: f32, zoom: f32) -> Self {
+        self.rig.look_sensitivity = look;
+        self.rig.zoom_sensitivity = zoom;
+        self
+    }
+
+    pub fn with_head_offset(mut self, head_offset: Vec3) -> Self {
+        self.rig.head_offset = head_offset;
+        self
+    }
+
+    pub fn with_shoulder_offset(mut self, offset: Vec2) -> Self {
+        self.rig.shoulder_offset = offset;
+        self
+    }
+
+    pub fn with_framing_offset(mut self, offset: Vec2) -> Self {
+        self.rig.framing_offset = offset;
+        self
+    }
+
+    pub fn with_roll(mut self, source: RollSource, auto_level: bool) -> Self {
+        self.rig.roll_source = source;
+        self.rig.auto_level = auto_level;
+        self
+    }
+
+    pub fn with_mode(mut self, mode: CameraMode) -> Self {
+        self.rig.mode = mode;
+        self
+    }
+
+    pub fn with_blend(mut self, duration: f32, easing: Easing) -> Self {
+        self.blend_duration = duration;
+        self.easing = easing;
+        self
+    }
+
+    pub fn with_fov(mut self, fov: f32) -> Self {
+        self.rig.lens.fov = fov;
+        self
+    }
+
+    pub fn with_clip_planes(mut self, near: f32, far: f32) -> Self {
+        self.rig.lens.near = near;
+        self.rig.lens.far = far;
+        self
+    }
+
+    /// Binds the rig to one device, for local multiplayer.
+    pub fn with_input_source(mut self, source: InputSource) -> Self {
+        self.input_source = Some(source);
+        self
+    }
+
+    /// Renders into pane `slot` of a split screen.
+    pub fn with_screen_slot(mut self, slot: u32) -> Self {
+        self.screen_slot = Some(ScreenSlot(slot));
+        self
+    }
+
+    /// Adds a rear-view inset; the rear camera is spawned on the first frame.
+    pub fn with_rear_view(mut self, rear_view: RearView) -> Self {
+        self.rear_view = Some(rear_view);
+        self
+    }
+
+    /// Spawns the camera with `projection` as is; the rig's lens is taken from it.
+    pub fn with_projection(mut self, projection: impl Into<Projection>) -> Self {
+        let projection = projection.into();
+        self.rig.lens = CameraLens::from_projection(&projection);
+        self.projection = Some(projection);
+        self
+    }
+
+    /// Spawns the camera and its pivot, returning the pivot entity.
+    pub fn spawn(self, commands: &mut Commands) -> Entity {
+        let pivot_transform = Transform::from_translation(self.target)
+            .with_rotation(Quat::from_rotation_y(-self.rig.yaw));
+        let camera_transform = pivot_transform * self.rig.local_camera_transform();
+
+        let projection = self.projection.unwrap_or_else(|| self.rig.lens.projection());
+        let camera_entity = commands
+            .spawn((Camera3d::default(), projection, camera_transform))
+            .id();
+
+        let mut state = CameraModeState::new(self.rig.mode, camera_transform);
+        state.blend_duration = self.blend_duration;
+        state.easing = self.easing;
+
+        let mut pivot = commands.spawn((
+            pivot_transform,
+            state,
+            CameraRig {
+                camera_entity: Some(camera_entity),
+                applied_lens: Some(self.rig.lens),
+                ..self.rig
+            },
+        ));
+        #[cfg(feature = "collision")]
+        pivot.insert(crate::collision::CameraCollision::default());
+        if let Some(source) = self.input_source {
+            pivot.insert(source);
+        }
+        if let Some(slot) = self.screen_slot {
+            pivot.insert(slot);
+        }
+        if let Some(rear_view) = self.rear_view {
+            pivot.insert(rear_view);
+        }
+
+        match self.rig.mode {
+            CameraMode::TopDown => {
+                pivot.insert(TopDown {
+                    focus: self.target,
+                    height: self.rig.camera_distance,
+                    yaw: PI - self.rig.yaw,
+                });
+            }
+            CameraMode::Isometric => {
+                pivot.insert(Isometric::facing(self.rig.yaw));
+            }
+            CameraMode::Fixed => {
+                pivot.insert(ActiveFixedCamera::default());
+            }
+            CameraMode::SideScroller => {
+                pivot.insert(SideScroller::following(self.target));
+            }
+            CameraMode::Turntable => {
+                pivot.insert(Turntable {
+                    focus: self.target,
+                    yaw: -self.rig.yaw,
+                    pitch: std::f32::consts::FRAC_PI_2 - self.rig.camera_pitch,
+                    distance: self.rig.camera_distance,
+                    ..default()
+                });
+            }
+            CameraMode::TwinStick => {
+                pivot.insert(TwinStick::default());
+            }
+            _ => {}
+        }
+        pivot.id()
+    }
+}
+
+pub trait SpawnOrbitCameraExt {
+    fn spawn_orbit_camera(&mut self, target: Vec3) -> Entity;
+}
+
+impl SpawnOrbitCameraExt for Commands<'_, '_> {
+    fn spawn_orbit_camera(&mut self, target: Vec3) -> Entity {
+        OrbitCameraBuilder::new(target).spawn(self)
+    }
+}
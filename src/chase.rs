@@ -0,0 +1,145 @@
+use bevy::prelude::*;
+
+use crate::{
+    angle::{
+        lerp_angle,
+        wrap_angle,
+    },
+    input_map::{
+        ActionInput,
+        CameraAction,
+    },
+    mode::{
+        CameraMode,
+        CameraModeState,
+        CameraSpring,
+    },
+    player::{
+        Player,
+        rig_player,
+    },
+    rig::CameraRig,
+    split_screen::InputSource,
+};
+
+#[derive(Component)]
+pub struct ChaseCamera {
+    pub target: Entity,
+    pub distance: f32,
+    /// Extra follow distance per unit of target speed.
+    pub distance_per_speed: f32,
+    pub max_distance: f32,
+    pub height: f32,
+    /// How quickly the camera closes on its follow point, per second.
+    pub stiffness: f32,
+    /// Slows `stiffness` down as the target speeds up, per unit of speed.
+    pub lag_per_speed: f32,
+    /// How quickly yaw turns to match the target's heading, per second.
+    pub heading_stiffness: f32,
+
+    pub yaw: f32,
+    pub position: Option<Vec3>,
+    pub last_target: Option<Vec3>,
+    pub speed: f32,
+}
+
+impl ChaseCamera {
+    pub fn new(target: Entity) -> Self {
+        Self {
+            target,
+            distance: 6.,
+            distance_per_speed: 0.15,
+            max_distance: 14.,
+            height: 2.,
+            stiffness: 8.,
+            lag_per_speed: 0.05,
+            heading_stiffness: 4.,
+
+            yaw: 0.,
+            position: None,
+            last_target: None,
+            speed: 0.,
+        }
+    }
+
+    pub fn follow_distance(&self) -> f32 {
+        (self.distance + self.speed * self.distance_per_speed).min(self.max_distance)
+    }
+
+    /// Pose for this frame. With `sprung` set the follow point is used as is, leaving the lag to a
+    /// `CameraSpring` on the rig.
+    pub fn follow(&mut self, target: &GlobalTransform, sprung: bool, delta_seconds: f32) -> Transform {
+        let target_position = target.translation();
+        if let Some(last) = self.last_target {
+            if delta_seconds > 0. {
+                self.speed = target_position.distance(last) / delta_seconds;
+            }
+        }
+        self.last_target = Some(target_position);
+
+        let heading = target.forward().with_y(0.);
+        if heading != Vec3::ZERO {
+            let heading_yaw = heading.x.atan2(heading.z);
+            let blend = 1. - (-self.heading_stiffness * delta_seconds).exp();
+            self.yaw = wrap_angle(lerp_angle(self.yaw, heading_yaw, blend));
+        }
+
+        let behind = -Vec3::new(self.yaw.sin(), 0., self.yaw.cos());
+        let goal = target_position + behind * self.follow_distance() + Vec3::Y * self.height;
+
+        let stiffness = self.stiffness / (1. + self.speed * self.lag_per_speed);
+        let position = match self.position {
+            Some(position) if !sprung => position.lerp(goal, 1. - (-stiffness * delta_seconds).exp()),
+            _ => goal,
+        };
+        self.position = Some(position);
+
+        Transform::from_translation(position).looking_at(target_position, Vec3::Y)
+    }
+}
+
+pub fn toggle_chase(
+    mut commands: Commands,
+    actions: ActionInput,
+    players: Query<(&Player, Entity)>,
+    mut query: Query<(Entity, &mut CameraRig, Option<&InputSource>)>,
+) {
+    for (pivot, mut rig, source) in query.iter_mut() {
+        if !actions.just_pressed_by(CameraAction::ToggleChase, source) {
+            continue;
+        }
+        if rig.mode == CameraMode::Chase {
+            rig.mode = CameraMode::Orbit;
+        } else {
+            let Some(player) = rig_player(players.iter(), pivot) else { continue };
+            commands.entity(pivot).insert(ChaseCamera::new(player));
+            rig.mode = CameraMode::Chase;
+        }
+    }
+}
+
+pub fn update_chase(
+    time: Res<Time>,
+    targets: Query<&GlobalTransform>,
+    mut rigs: Query<(&CameraRig, &mut ChaseCamera, &mut CameraModeState, Has<CameraSpring>)>,
+) {
+    for (rig, mut chase, mut state, sprung) in rigs.iter_mut() {
+        if rig.mode != CameraMode::Chase {
+            chase.position = None;
+            chase.last_target = None;
+            continue;
+        }
+
+        let Ok(target) = targets.get(chase.target) else {
+            continue;
+        };
+
+        if chase.position.is_none() {
+            let current = state.current();
+            let heading = current.forward().with_y(0.);
+            chase.position = Some(current.translation);
+            chase.yaw = heading.x.atan2(heading.z);
+        }
+        state.desired = chase.follow(target, sprung, time.delta_secs());
+    }
+}
@@ -0,0 +1,517 @@
+use bevy::{
+    prelude::*,
+    math::bounding::{
+        Aabb3d,
+        BoundingVolume,
+        RayCast3d,
+    },
+    render::primitives::Aabb,
+};
+
+use crate::{
+    fade::MaterialFade,
+    input::MouseDeltas,
+    mode::{
+        CameraMode,
+        CameraModeState,
+    },
+    rig::CameraRig,
+    split_screen::{
+        InputSource,
+        accepts_keyboard_mouse,
+    },
+};
+#[cfg(feature = "gamepad")]
+use crate::gamepad::GamepadAxes;
+
+/// Geometry the camera can't pass through, using the entity's mesh bounds.
+#[derive(Component, Default)]
+pub struct CameraCollider;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum CollisionProbe {
+    /// A single ray from the pivot to the camera.
+    #[default]
+    Ray,
+    /// A sphere of the given radius swept along the arm.
+    Sphere(f32),
+    /// A sphere just covering the camera's near plane.
+    NearPlane,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OcclusionStrategy {
+    /// Shorten the arm in front of whatever blocks it.
+    #[default]
+    PullIn,
+    /// Keep the arm and fade out whatever is between the camera and the pivot.
+    Fade,
+}
+
+/// Spring arm that shortens the orbit distance when geometry is in the way.
+#[derive(Component)]
+pub struct CameraCollision {
+    /// Gap kept between the camera and the surface it hit.
+    pub skin_width: f32,
+    /// How fast the arm pulls in toward a hit, in units per second.
+    pub shorten_speed: f32,
+    /// How quickly the arm eases back out once the path clears, per second.
+    pub recover_speed: f32,
+    pub probe: CollisionProbe,
+    pub strategy: OcclusionStrategy,
+    /// Opacity occluders fade to under `OcclusionStrategy::Fade`.
+    pub fade_alpha: f32,
+    /// Opacity change per second while fading.
+    pub fade_speed: f32,
+    /// Fade with alpha-to-coverage, which dithers under MSAA, instead of blending.
+    pub dither: bool,
+    /// Height the camera keeps above colliders below it.
+    pub ground_clearance: f32,
+    /// World height the camera never goes below, whatever is under it.
+    pub floor_height: Option<f32>,
+    /// Current arm length from the pivot.
+    pub distance: Option<f32>,
+}
+
+impl Default for CameraCollision {
+    fn default() -> Self {
+        Self {
+            skin_width: 0.2,
+            shorten_speed: 50.,
+            recover_speed: 2.,
+            probe: CollisionProbe::Ray,
+            strategy: OcclusionStrategy::PullIn,
+            fade_alpha: 0.25,
+            fade_speed: 4.,
+            dither: false,
+            ground_clearance: 0.3,
+            floor_height: Some(0.),
+            distance: None,
+        }
+    }
+}
+
+/// Angled rays either side of the arm that steer the camera around walls
+/// before the arm itself is blocked.
+#[derive(Component)]
+pub struct Whiskers {
+    /// Angles of the whisker pairs from the arm, in radians.
+    pub angles: Vec<f32>,
+    /// Yaw rate applied when a side is fully blocked, in radians per second.
+    pub yaw_bias: f32,
+    /// Fraction of the way the arm is pulled in toward the nearest whisker hit.
+    pub distance_bias: f32,
+    /// How quickly the pull follows the whiskers, per second.
+    pub stiffness: f32,
+    pub pull: Option<f32>,
+    /// How far the whiskers drew the arm in this frame.
+    pub shortened: f32,
+}
+
+impl Default for Whiskers {
+    fn default() -> Self {
+        Self {
+            angles: vec![15f32.to_radians(), 30f32.to_radians()],
+            yaw_bias: 1.5,
+            distance_bias: 0.5,
+            stiffness: 4.,
+            pull: None,
+            shortened: 0.,
+        }
+    }
+}
+
+/// A horizontal surface, such as water, at the entity's height that the
+/// camera stays above while the pivot is above it.
+#[derive(Component)]
+#[require(Transform)]
+pub struct SurfacePlane {
+    pub clearance: f32,
+}
+
+impl Default for SurfacePlane {
+    fn default() -> Self {
+        Self {
+            clearance: 0.3,
+        }
+    }
+}
+
+/// A collider currently drawn with a faded copy of its material.
+#[derive(Component)]
+pub struct FadedOccluder {
+    pub fade: MaterialFade,
+    pub alpha: f32,
+    pub target_alpha: f32,
+    pub speed: f32,
+    pub occluding: bool,
+}
+
+/// Swings the orbit toward a clear view once the target stays hidden too long.
+#[derive(Component)]
+pub struct OcclusionAssist {
+    /// Seconds the target must stay occluded before the assist starts.
+    pub delay: f32,
+    /// Yaw rate used to swing toward the clear angle, in radians per second.
+    pub speed: f32,
+    /// Spacing of the sampled yaw angles, in radians.
+    pub sample_step: f32,
+    /// Samples taken either side of the current yaw.
+    pub samples: u32,
+    pub occluded_for: f32,
+}
+
+impl Default for OcclusionAssist {
+    fn default() -> Self {
+        Self {
+            delay: 0.4,
+            speed: 1.5,
+            sample_step: 15f32.to_radians(),
+            samples: 12,
+            occluded_for: 0.,
+        }
+    }
+}
+
+pub fn world_aabb(aabb: &Aabb, transform: &GlobalTransform) -> Aabb3d {
+    let (scale, rotation, translation) = transform.to_scale_rotation_translation();
+    Aabb3d::new(Vec3::from(aabb.center) * scale, Vec3::from(aabb.half_extents) * scale.abs())
+        .transformed_by(translation, rotation)
+}
+
+/// Nearest collider hit along the ray, ignoring colliders the ray starts inside.
+pub fn cast_ray(colliders: &[Aabb3d], origin: Vec3, direction: Dir3, max: f32) -> Option<f32> {
+    let ray = RayCast3d::new(origin, direction, max);
+    colliders
+        .iter()
+        .filter_map(|aabb| ray.aabb_intersection_at(aabb))
+        .filter(|distance| *distance > 0.)
+        .min_by(f32::total_cmp)
+}
+
+/// Like `cast_ray`, but for a sphere of `radius` swept along the ray.
+pub fn cast_sphere(colliders: &[Aabb3d], origin: Vec3, direction: Dir3, max: f32, radius: f32) -> Option<f32> {
+    let grown: Vec<Aabb3d> = colliders.iter().map(|aabb| aabb.grow(Vec3::splat(radius))).collect();
+    cast_ray(&grown, origin, direction, max)
+}
+
+/// Radius of the sphere enclosing the near plane of a perspective projection.
+pub fn near_plane_radius(projection: &Projection) -> f32 {
+    match projection {
+        Projection::Perspective(perspective) => {
+            let half_height = perspective.near * (perspective.fov / 2.).tan();
+            half_height * (1. + perspective.aspect_ratio * perspective.aspect_ratio).sqrt()
+        }
+        Projection::Orthographic(orthographic) => orthographic.near.abs(),
+    }
+}
+
+pub fn collect_colliders(colliders: &Query<(&Aabb, &GlobalTransform), With<CameraCollider>>) -> Vec<Aabb3d> {
+    colliders
+        .iter()
+        .map(|(aabb, transform)| world_aabb(aabb, transform))
+        .collect()
+}
+
+pub fn update_occlusion_assist(
+    time: Res<Time>,
+    deltas: Res<MouseDeltas>,
+    #[cfg(feature = "gamepad")]
+    gamepad: Res<GamepadAxes>,
+    colliders: Query<(&Aabb, &GlobalTransform), With<CameraCollider>>,
+    mut rigs: Query<(&mut CameraRig, &Transform, &mut OcclusionAssist, &CameraModeState, Option<&InputSource>)>,
+) {
+    let colliders = collect_colliders(&colliders);
+    let delta_seconds = time.delta_secs();
+
+    for (mut rig, pivot, mut assist, state, source) in rigs.iter_mut() {
+        let looking = accepts_keyboard_mouse(source) && deltas.motion != Vec2::ZERO;
+        #[cfg(feature = "gamepad")]
+        let looking = looking || gamepad.for_source(source).look != Vec2::ZERO;
+
+        if rig.mode != CameraMode::Orbit || looking {
+            assist.occluded_for = 0.;
+            continue;
+        }
+
+        let offset = state.desired.translation - pivot.translation;
+        let Ok(direction) = Dir3::new(offset) else {
+            continue;
+        };
+        let length = offset.length();
+        let blocked = |angle: f32| {
+            let ray = Dir3::new_unchecked(Quat::from_rotation_y(angle) * *direction);
+            cast_ray(&colliders, pivot.translation, ray, length).is_some()
+        };
+
+        if !blocked(0.) {
+            assist.occluded_for = 0.;
+            continue;
+        }
+
+        assist.occluded_for += delta_seconds;
+        if assist.occluded_for < assist.delay {
+            continue;
+        }
+
+        let clear = (1..=assist.samples)
+            .flat_map(|i| {
+                let angle = i as f32 * assist.sample_step;
+                [angle, -angle]
+            })
+            .find(|angle| !blocked(*angle));
+
+        if let Some(angle) = clear {
+            // The pivot turns by -yaw, so swinging the arm by +angle means lowering yaw.
+            let step = assist.speed * delta_seconds;
+            rig.yaw -= angle.clamp(-step, step);
+        }
+    }
+}
+
+pub fn update_whiskers(
+    time: Res<Time>,
+    colliders: Query<(&Aabb, &GlobalTransform), With<CameraCollider>>,
+    mut rigs: Query<(&mut CameraRig, &Transform, &mut Whiskers, &mut CameraModeState)>,
+) {
+    let colliders = collect_colliders(&colliders);
+    let delta_seconds = time.delta_secs();
+
+    for (mut rig, pivot, mut whiskers, mut state) in rigs.iter_mut() {
+        whiskers.shortened = 0.;
+        if rig.mode != CameraMode::Orbit {
+            whiskers.pull = None;
+            continue;
+        }
+
+        let offset = state.desired.translation - pivot.translation;
+        let Ok(direction) = Dir3::new(offset) else {
+            continue;
+        };
+        let wanted = offset.length();
+
+        let mut nearest = wanted;
+        let mut steer = 0.;
+        for &angle in whiskers.angles.iter() {
+            for side in [1., -1.] {
+                let whisker = Dir3::new_unchecked(Quat::from_rotation_y(side * angle) * *direction);
+                if let Some(hit) = cast_ray(&colliders, pivot.translation, whisker, wanted) {
+                    nearest = nearest.min(hit);
+                    steer += side * (1. - hit / wanted);
+                }
+            }
+        }
+
+        // The pivot turns by -yaw, so a positive yaw step swings the arm away from the + side.
+        rig.yaw += steer.clamp(-1., 1.) * whiskers.yaw_bias * delta_seconds;
+
+        let target = wanted - (wanted - nearest) * whiskers.distance_bias;
+        let blend = 1. - (-whiskers.stiffness * delta_seconds).exp();
+        let pull = match whiskers.pull {
+            Some(pull) => pull + (target - pull) * blend,
+            None => target,
+        };
+        whiskers.pull = Some(pull);
+        whiskers.shortened = wanted - pull.min(wanted);
+
+        state.desired.translation = pivot.translation + direction * pull.min(wanted);
+    }
+}
+
+pub fn update_spring_arm(
+    time: Res<Time>,
+    colliders: Query<(&Aabb, &GlobalTransform), With<CameraCollider>>,
+    projections: Query<&Projection>,
+    mut rigs: Query<(&mut CameraRig, &Transform, &mut CameraCollision, &mut CameraModeState, Option<&Whiskers>)>,
+) {
+    let colliders = collect_colliders(&colliders);
+
+    for (mut rig, pivot, mut collision, mut state, whiskers) in rigs.iter_mut() {
+        if rig.mode != CameraMode::Orbit || collision.strategy != OcclusionStrategy::PullIn {
+            collision.distance = None;
+            rig.collision_distance = None;
+            continue;
+        }
+
+        let offset = state.desired.translation - pivot.translation;
+        let Ok(direction) = Dir3::new(offset) else {
+            continue;
+        };
+        let wanted = offset.length();
+
+        let radius = match collision.probe {
+            CollisionProbe::Ray => 0.,
+            CollisionProbe::Sphere(radius) => radius,
+            CollisionProbe::NearPlane => rig.camera_entity
+                .and_then(|camera| projections.get(camera).ok())
+                .map(near_plane_radius)
+                .unwrap_or(0.),
+        };
+
+        let max = wanted + collision.skin_width;
+        let hit = if radius > 0. {
+            cast_sphere(&colliders, pivot.translation, direction, max, radius)
+        } else {
+            cast_ray(&colliders, pivot.translation, direction, max)
+        };
+
+        let clear = hit.map(|hit| (hit - collision.skin_width).max(0.));
+        let allowed = clear.unwrap_or(wanted).min(wanted);
+        // In `camera_distance` terms, which differ from the arm length by the shoulder and focus offsets,
+        // measured against the arm before the whiskers drew it in.
+        let unshortened = wanted + whiskers.map_or(0., |whiskers| whiskers.shortened);
+        rig.collision_distance = clear.map(|clear| clear + rig.camera_distance - unshortened);
+
+        let delta_seconds = time.delta_secs();
+        let distance = match collision.distance {
+            Some(distance) if distance < allowed => {
+                let blend = 1. - (-collision.recover_speed * delta_seconds).exp();
+                distance + (allowed - distance) * blend
+            }
+            Some(distance) => (distance - collision.shorten_speed * delta_seconds).max(allowed),
+            None => allowed,
+        };
+        collision.distance = Some(distance);
+
+        state.desired.translation = pivot.translation + direction * distance;
+    }
+}
+
+pub fn clamp_to_ground(
+    colliders: Query<(&Aabb, &GlobalTransform), With<CameraCollider>>,
+    mut rigs: Query<(&CameraRig, &Transform, &CameraCollision, &mut CameraModeState)>,
+) {
+    let colliders = collect_colliders(&colliders);
+
+    for (rig, pivot, collision, mut state) in rigs.iter_mut() {
+        if !rig.mode.follows_pivot() || rig.mode == CameraMode::FirstPerson {
+            continue;
+        }
+
+        let camera = state.desired.translation;
+        // Probe down from no lower than the pivot, so a camera already under the ground still finds it.
+        let origin = camera.with_y(camera.y.max(pivot.translation.y));
+        let ground = cast_ray(&colliders, origin, Dir3::NEG_Y, f32::MAX)
+            .map(|hit| origin.y - hit + collision.ground_clearance);
+
+        let min_height = match (ground, collision.floor_height) {
+            (Some(ground), Some(floor)) => ground.max(floor + collision.ground_clearance),
+            (Some(ground), None) => ground,
+            (None, Some(floor)) => floor + collision.ground_clearance,
+            (None, None) => continue,
+        };
+
+        if camera.y < min_height {
+            state.desired.translation.y = min_height;
+        }
+    }
+}
+
+pub fn avoid_surfaces(
+    surfaces: Query<(&SurfacePlane, &GlobalTransform)>,
+    mut rigs: Query<(&CameraRig, &Transform, &mut CameraModeState)>,
+) {
+    for (rig, pivot, mut state) in rigs.iter_mut() {
+        if !rig.mode.follows_pivot() || rig.mode == CameraMode::FirstPerson {
+            continue;
+        }
+
+        for (surface, transform) in surfaces.iter() {
+            let height = transform.translation().y;
+            if pivot.translation.y <= height {
+                continue;
+            }
+
+            let min_height = height + surface.clearance;
+            if state.desired.translation.y < min_height {
+                state.desired.translation.y = min_height;
+            }
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn detect_occluders(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut colliders: Query<(
+        Entity,
+        &Aabb,
+        &GlobalTransform,
+        Option<&mut MeshMaterial3d<StandardMaterial>>,
+        Option<&mut FadedOccluder>,
+    ), With<CameraCollider>>,
+    rigs: Query<(&CameraRig, &Transform, &CameraCollision, &CameraModeState)>,
+) {
+    let rays: Vec<(RayCast3d, &CameraCollision)> = rigs
+        .iter()
+        .filter(|(rig, _, collision, _)| {
+            collision.strategy == OcclusionStrategy::Fade
+                && rig.mode.follows_pivot()
+                && rig.mode != CameraMode::FirstPerson
+        })
+        .filter_map(|(_, pivot, collision, state)| {
+            let offset = pivot.translation - state.desired.translation;
+            let direction = Dir3::new(offset).ok()?;
+            Some((RayCast3d::new(state.desired.translation, direction, offset.length()), collision))
+        })
+        .collect();
+
+    for (entity, aabb, transform, material, faded) in colliders.iter_mut() {
+        let bounds = world_aabb(aabb, transform);
+        let occluded_by = rays
+            .iter()
+            .find(|(ray, _)| ray.aabb_intersection_at(&bounds).is_some())
+            .map(|(_, collision)| *collision);
+
+        match (faded, occluded_by) {
+            (Some(mut faded), Some(collision)) => {
+                faded.occluding = true;
+                faded.target_alpha = collision.fade_alpha;
+                faded.speed = collision.fade_speed;
+            }
+            (Some(mut faded), None) => {
+                faded.occluding = false;
+            }
+            (None, Some(collision)) => {
+                let Some(mut material) = material else {
+                    continue;
+                };
+                let alpha_mode = if collision.dither { AlphaMode::AlphaToCoverage } else { AlphaMode::Blend };
+                let Some(fade) = MaterialFade::begin(&mut materials, &mut material.0, alpha_mode, 1.) else {
+                    continue;
+                };
+
+                commands.entity(entity).insert(FadedOccluder {
+                    fade,
+                    alpha: 1.,
+                    target_alpha: collision.fade_alpha,
+                    speed: collision.fade_speed,
+                    occluding: true,
+                });
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+pub fn fade_occluders(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut occluders: Query<(Entity, &mut FadedOccluder, &mut MeshMaterial3d<StandardMaterial>)>,
+) {
+    for (entity, mut faded, mut material) in occluders.iter_mut() {
+        let target = if faded.occluding { faded.target_alpha } else { 1. };
+        let step = faded.speed * time.delta_secs();
+        faded.alpha += (target - faded.alpha).clamp(-step, step);
+
+        if !faded.occluding && faded.alpha >= 1. {
+            faded.fade.restore(&mut materials, &mut material.0);
+            commands.entity(entity).remove::<FadedOccluder>();
+            continue;
+        }
+
+        faded.fade.set_opacity(&mut materials, &material.0, faded.alpha);
+    }
+}
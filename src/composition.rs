@@ -0,0 +1,115 @@
+use bevy::prelude::*;
+
+use crate::{
+    mode::{
+        CameraMode,
+        CameraModeState,
+    },
+    player::{
+        Player,
+        rig_velocity,
+    },
+    rig::CameraRig,
+};
+
+/// Screen offset of a rule-of-thirds line from the centre, in normalized coordinates.
+pub const THIRD: f32 = 1. / 3.;
+
+/// Where on screen the pivot should sit, in normalized coordinates from -1 to 1 with +y up.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum CompositionPreset {
+    #[default]
+    Centered,
+    Fixed(Vec2),
+    /// On the vertical third behind the direction of travel, leaving room ahead, at `height`.
+    Leading { height: f32 },
+}
+
+impl CompositionPreset {
+    pub fn lower_left_third() -> Self {
+        CompositionPreset::Fixed(Vec2::new(-THIRD, -THIRD))
+    }
+
+    pub fn lower_right_third() -> Self {
+        CompositionPreset::Fixed(Vec2::new(THIRD, -THIRD))
+    }
+
+    pub fn lower_third() -> Self {
+        CompositionPreset::Fixed(Vec2::new(0., -THIRD))
+    }
+}
+
+/// Drives the rig's `framing_offset` so the pivot lands on a composition preset.
+#[derive(Component)]
+pub struct Composition {
+    pub preset: CompositionPreset,
+    /// How quickly the screen position moves to a new preset or side, per second.
+    pub transition_rate: f32,
+    /// Sideways screen speed, in units per second, needed before `Leading` switches sides.
+    pub switch_speed: f32,
+
+    pub screen_position: Vec2,
+    pub side: f32,
+}
+
+impl Default for Composition {
+    fn default() -> Self {
+        Self::new(CompositionPreset::default())
+    }
+}
+
+impl Composition {
+    pub fn new(preset: CompositionPreset) -> Self {
+        Self {
+            preset,
+            transition_rate: 3.,
+            switch_speed: 0.5,
+
+            screen_position: Vec2::ZERO,
+            side: 1.,
+        }
+    }
+
+    pub fn target(&self) -> Vec2 {
+        match self.preset {
+            CompositionPreset::Centered => Vec2::ZERO,
+            CompositionPreset::Fixed(position) => position,
+            CompositionPreset::Leading { height } => Vec2::new(-self.side * THIRD, height),
+        }
+    }
+}
+
+pub fn update_composition(
+    time: Res<Time>,
+    projections: Query<&Projection>,
+    players: Query<&Player>,
+    mut rigs: Query<(Entity, &mut CameraRig, &mut Composition, &CameraModeState)>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for (entity, mut rig, mut composition, state) in rigs.iter_mut() {
+        if !rig.mode.follows_pivot() || rig.mode == CameraMode::FirstPerson {
+            continue;
+        }
+
+        let right = state.current().rotation * Vec3::X;
+        let sideways = rig_velocity(&players, entity).dot(right);
+        if sideways.abs() >= composition.switch_speed {
+            composition.side = sideways.signum();
+        }
+
+        let blend = 1. - (-composition.transition_rate * delta_seconds).exp();
+        let target = composition.target();
+        composition.screen_position = composition.screen_position.lerp(target, blend);
+
+        let (fov, aspect_ratio) = match rig.camera_entity.and_then(|entity| projections.get(entity).ok()) {
+            Some(Projection::Perspective(perspective)) => (perspective.fov, perspective.aspect_ratio),
+            _ => (std::f32::consts::FRAC_PI_4, 1.),
+        };
+
+        // Aiming away from the pivot by this much at the pivot's distance moves it the other way on screen.
+        let half_height = (fov / 2.).tan() * rig.camera_distance;
+        let half_extent = Vec2::new(half_height * aspect_ratio, half_height);
+        rig.framing_offset = -composition.screen_position * half_extent;
+    }
+}
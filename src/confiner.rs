@@ -0,0 +1,76 @@
+use bevy::prelude::*;
+
+use crate::rig::CameraRig;
+
+#[derive(Clone, Debug)]
+pub enum ConfinerShape {
+    /// Half extents of a box centred on the volume.
+    Box(Vec3),
+    /// Local-space half-spaces `normal · p <= distance` enclosing a convex volume.
+    Convex(Vec<(Vec3, f32)>),
+}
+
+/// A volume cameras with a `CameraConfiner` pointing at it can't leave.
+#[derive(Component)]
+#[require(Transform)]
+pub struct ConfinerVolume {
+    pub shape: ConfinerShape,
+}
+
+impl ConfinerVolume {
+    pub fn clamp(&self, volume_transform: &GlobalTransform, point: Vec3) -> Vec3 {
+        let affine = volume_transform.affine();
+        let mut local = affine.inverse().transform_point3(point);
+
+        match &self.shape {
+            ConfinerShape::Box(half_extents) => {
+                local = local.clamp(-*half_extents, *half_extents);
+            }
+            ConfinerShape::Convex(planes) => {
+                // Repeated projection converges on the nearest inside point for well-shaped volumes.
+                for _ in 0..8 {
+                    let mut moved = false;
+                    for (normal, distance) in planes.iter() {
+                        let normal = normal.normalize_or_zero();
+                        let excess = normal.dot(local) - distance;
+                        if excess > 0. {
+                            local -= normal * excess;
+                            moved = true;
+                        }
+                    }
+                    if !moved {
+                        break;
+                    }
+                }
+            }
+        }
+
+        affine.transform_point3(local)
+    }
+}
+
+/// Keeps the rig's camera inside `volume`.
+#[derive(Component)]
+pub struct CameraConfiner {
+    pub volume: Entity,
+}
+
+pub fn confine_cameras(
+    rigs: Query<(&CameraRig, &CameraConfiner)>,
+    volumes: Query<(&ConfinerVolume, &GlobalTransform)>,
+    mut cameras: Query<&mut Transform, Without<CameraRig>>,
+) {
+    for (rig, confiner) in rigs.iter() {
+        let Ok((volume, volume_transform)) = volumes.get(confiner.volume) else {
+            continue;
+        };
+        let Some(mut transform) = rig.camera_entity.and_then(|camera| cameras.get_mut(camera).ok()) else {
+            continue;
+        };
+
+        let confined = volume.clamp(volume_transform, transform.translation);
+        if confined != transform.translation {
+            transform.translation = confined;
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use bevy::{
+    prelude::*,
+    window::{
+        CursorGrabMode,
+        PrimaryWindow,
+    },
+};
+
+use crate::{
+    input_map::{
+        ActionInput,
+        CameraAction,
+    },
+    mode::CameraMode,
+    rig::CameraRig,
+    settings::{
+        CameraSettings,
+        LookMode,
+    },
+};
+
+/// Whether mouse motion currently drives the camera; the cursor is hidden and grabbed while it does.
+#[derive(Resource)]
+pub struct CursorLook {
+    pub active: bool,
+}
+
+impl Default for CursorLook {
+    fn default() -> Self {
+        Self {
+            active: true,
+        }
+    }
+}
+
+/// World ray under the cursor for `camera`. A grabbed cursor sits at the window centre, whatever
+/// position it last reported.
+pub fn cursor_ray(window: &Window, camera: &Camera, camera_transform: &GlobalTransform) -> Option<Ray3d> {
+    let position = if window.cursor_options.grab_mode != CursorGrabMode::None {
+        window.size() / 2.
+    } else {
+        window.cursor_position()?
+    };
+    camera.viewport_to_world(camera_transform, position).ok()
+}
+
+pub fn grab_cursor(
+    settings: Res<CameraSettings>,
+    actions: ActionInput,
+    rigs: Query<&CameraRig>,
+    mut look: ResMut<CursorLook>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let Ok(mut window) = windows.get_single_mut() else {
+        return;
+    };
+
+    let wants_look = rigs.iter().any(|rig| {
+        (rig.mode.follows_pivot() && rig.mode != CameraMode::Isometric) || rig.mode == CameraMode::FreeFly
+    });
+
+    look.active = match settings.look_mode {
+        LookMode::AlwaysLocked => {
+            if !window.focused || actions.just_pressed(CameraAction::ReleaseCursor) {
+                false
+            } else if actions.just_pressed(CameraAction::GrabCursor) {
+                true
+            } else {
+                look.active
+            }
+        }
+        LookMode::HoldToLook => window.focused && actions.pressed(CameraAction::Look),
+    };
+    let grabbed = look.active && wants_look;
+
+    let grab_mode = if !grabbed {
+        CursorGrabMode::None
+    } else if cfg!(target_os = "windows") {
+        CursorGrabMode::Confined
+    } else {
+        CursorGrabMode::Locked
+    };
+
+    if window.cursor_options.grab_mode != grab_mode {
+        window.cursor_options.grab_mode = grab_mode;
+        window.cursor_options.visible = !grabbed;
+    }
+}
@@ -0,0 +1,51 @@
+use std::ops::RangeInclusive;
+
+use bevy::prelude::*;
+use bevy_egui::{
+    egui,
+    EguiContexts,
+};
+
+use crate::{
+    rig::CameraRig,
+    settings::CameraSettings,
+};
+
+fn angle_slider(ui: &mut egui::Ui, value: &mut f32, range: RangeInclusive<f32>, text: &str) {
+    let mut degrees = value.to_degrees();
+    if ui.add(egui::Slider::new(&mut degrees, range).text(text)).changed() {
+        *value = degrees.to_radians();
+    }
+}
+
+pub fn rig_inspector(
+    mut contexts: EguiContexts,
+    mut settings: ResMut<CameraSettings>,
+    mut rigs: Query<(Entity, &mut CameraRig)>,
+) {
+    egui::Window::new("Camera").show(contexts.ctx_mut(), |ui| {
+        ui.add(egui::Slider::new(&mut settings.move_speed, 0.0..=50.0).text("move speed"));
+
+        for (entity, mut rig) in rigs.iter_mut() {
+            let rig = &mut *rig;
+            ui.collapsing(format!("rig {entity}"), |ui| {
+                angle_slider(ui, &mut rig.yaw, -180.0..=180.0, "yaw");
+                angle_slider(ui, &mut rig.camera_pitch, 0.0..=180.0, "pitch");
+                ui.add(egui::Slider::new(&mut rig.target_distance, rig.min_distance..=rig.max_distance).text("distance"));
+                ui.label(format!("mode: {:?}", rig.mode));
+                ui.checkbox(&mut rig.left_shoulder, "left shoulder");
+
+                ui.separator();
+                ui.add(egui::Slider::new(&mut rig.min_distance, 0.0..=100.0).text("min distance"));
+                ui.add(egui::Slider::new(&mut rig.max_distance, 0.0..=100.0).text("max distance"));
+                angle_slider(ui, &mut rig.min_pitch, 0.0..=180.0, "min pitch");
+                angle_slider(ui, &mut rig.max_pitch, 0.0..=180.0, "max pitch");
+                angle_slider(ui, &mut rig.soft_pitch_zone, 0.0..=45.0, "soft pitch zone");
+                ui.add(egui::Slider::new(&mut rig.look_sensitivity, 0.0..=0.02).text("look sensitivity"));
+                ui.add(egui::Slider::new(&mut rig.zoom_sensitivity, 0.0..=10.0).text("zoom sensitivity"));
+                ui.add(egui::Slider::new(&mut rig.shoulder_offset.x, 0.0..=5.0).text("shoulder lateral"));
+                ui.add(egui::Slider::new(&mut rig.shoulder_offset.y, -2.0..=5.0).text("shoulder vertical"));
+            });
+        }
+    });
+}
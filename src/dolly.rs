@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+
+use crate::{
+    easing::Easing,
+    rig::CameraRig,
+    settings::CameraSettings,
+};
+
+/// Starts a dolly zoom on `rig`: the field of view changes by `intensity` radians over `duration`
+/// while the distance moves to keep the pivot the same size on screen. Positive widens the lens and
+/// pulls the camera in.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct DollyZoom {
+    pub rig: Entity,
+    pub duration: f32,
+    pub intensity: f32,
+}
+
+/// A dolly zoom in progress; removed once it finishes.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct DollyZoomEffect {
+    pub from_fov: f32,
+    pub to_fov: f32,
+    /// Half the view's height at the pivot, held constant for the whole effect.
+    pub half_height: f32,
+    pub duration: f32,
+    pub easing: Easing,
+    pub elapsed: f32,
+}
+
+pub fn start_dolly_zoom(
+    mut commands: Commands,
+    settings: Res<CameraSettings>,
+    mut events: EventReader<DollyZoom>,
+    rigs: Query<&CameraRig>,
+    projections: Query<&Projection>,
+) {
+    for event in events.read() {
+        let Ok(rig) = rigs.get(event.rig) else { continue };
+        let Some(fov) = rig.camera_fov(&projections) else { continue };
+
+        commands.entity(event.rig).insert(DollyZoomEffect {
+            from_fov: fov,
+            to_fov: (fov + event.intensity).clamp(settings.min_fov, settings.max_fov),
+            half_height: rig.camera_distance * (fov / 2.).tan(),
+            duration: event.duration,
+            easing: Easing::EaseInOutCubic,
+            elapsed: 0.,
+        });
+    }
+}
+
+pub fn update_dolly_zoom(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut rigs: Query<(Entity, &mut CameraRig, &mut DollyZoomEffect)>,
+    mut projections: Query<&mut Projection>,
+) {
+    for (entity, mut rig, mut effect) in rigs.iter_mut() {
+        effect.elapsed += time.delta_secs();
+        let t = if effect.duration > 0. { effect.elapsed / effect.duration } else { 1. };
+        let fov = effect.from_fov.lerp(effect.to_fov, effect.easing.apply(t));
+
+        let Some(mut projection) = rig.camera_entity.and_then(|camera| projections.get_mut(camera).ok()) else {
+            commands.entity(entity).remove::<DollyZoomEffect>();
+            continue;
+        };
+        if let Projection::Perspective(perspective) = projection.as_mut() {
+            perspective.fov = fov;
+        }
+        // Still clamped to the rig's distance limits, which cut the effect short if they're tight.
+        rig.set_distance(effect.half_height / (fov / 2.).tan());
+        rig.target_fov = rig.target_fov.map(|_| fov);
+
+        if t >= 1. {
+            commands.entity(entity).remove::<DollyZoomEffect>();
+        }
+    }
+}
@@ -0,0 +1,58 @@
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Easing {
+    Linear,
+    #[default]
+    SmoothStep,
+    EaseInCubic,
+    EaseOutCubic,
+    EaseInOutCubic,
+}
+
+impl Easing {
+    /// Maps `t` in `[0, 1]` onto the curve; values outside are clamped.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0., 1.);
+        match self {
+            Easing::Linear => t,
+            Easing::SmoothStep => smoothstep(t),
+            Easing::EaseInCubic => t * t * t,
+            Easing::EaseOutCubic => 1. - (1. - t).powi(3),
+            Easing::EaseInOutCubic => {
+                if t < 0.5 {
+                    4. * t * t * t
+                } else {
+                    1. - (-2. * t + 2.).powi(3) / 2.
+                }
+            }
+        }
+    }
+}
+
+/// Fraction of the remaining gap to close this frame so that half of it is gone every `half_life` seconds.
+pub fn decay_blend(half_life: f32, delta_seconds: f32) -> f32 {
+    if half_life <= 0. {
+        return 1.;
+    }
+    1. - 0.5f32.powf(delta_seconds / half_life)
+}
+
+/// Critically damped approach of `current` towards `target`, reaching it in roughly `smooth_time`
+/// seconds without overshoot; `velocity` carries the motion between frames.
+pub fn smooth_damp(current: f32, target: f32, velocity: &mut f32, smooth_time: f32, delta_seconds: f32) -> f32 {
+    if smooth_time <= 0. {
+        *velocity = 0.;
+        return target;
+    }
+
+    let omega = 2. / smooth_time;
+    let x = omega * delta_seconds;
+    let decay = 1. / (1. + x + 0.48 * x * x + 0.235 * x * x * x);
+    let change = current - target;
+    let temp = (*velocity + omega * change) * delta_seconds;
+    *velocity = (*velocity - omega * temp) * decay;
+    target + (change + temp) * decay
+}
+
+pub fn smoothstep(t: f32) -> f32 {
+    t * t * (3. - 2. * t)
+}
@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+
+/// A translucent copy of a material standing in for the original while an entity is faded.
+#[derive(Clone, Debug)]
+pub struct MaterialFade {
+    pub original: Handle<StandardMaterial>,
+    pub original_alpha: f32,
+}
+
+impl MaterialFade {
+    /// Swaps `material` for a copy drawn with `alpha_mode` at `opacity`; `None` while the asset isn't loaded.
+    pub fn begin(
+        materials: &mut Assets<StandardMaterial>,
+        material: &mut Handle<StandardMaterial>,
+        alpha_mode: AlphaMode,
+        opacity: f32,
+    ) -> Option<Self> {
+        let mut copy = materials.get(&*material)?.clone();
+        let original_alpha = copy.base_color.alpha();
+        copy.alpha_mode = alpha_mode;
+        copy.base_color.set_alpha(original_alpha * opacity);
+
+        let original = std::mem::replace(material, materials.add(copy));
+        Some(Self { original, original_alpha })
+    }
+
+    /// Scales the copy's alpha to `opacity` of the original's.
+    pub fn set_opacity(&self, materials: &mut Assets<StandardMaterial>, material: &Handle<StandardMaterial>, opacity: f32) {
+        if let Some(copy) = materials.get_mut(material) {
+            copy.base_color.set_alpha(self.original_alpha * opacity);
+        }
+    }
+
+    /// Drops the copy and puts the original material back.
+    pub fn restore(&self, materials: &mut Assets<StandardMaterial>, material: &mut Handle<StandardMaterial>) {
+        materials.remove(&*material);
+        *material = self.original.clone();
+    }
+}
@@ -0,0 +1,84 @@
+use bevy::prelude::*;
+
+use crate::{
+    input_map::{
+        ActionInput,
+        CameraAction,
+    },
+    mode::{
+        CameraMode,
+        CameraModeState,
+    },
+    player::{
+        Player,
+        rig_player,
+    },
+    rig::CameraRig,
+    split_screen::InputSource,
+};
+
+/// A static viewpoint; its transform is used as the camera pose while active.
+#[derive(Component, Default)]
+#[require(Transform)]
+pub struct FixedCamera;
+
+/// An oriented box that activates `camera` when the player is inside it.
+#[derive(Component)]
+#[require(Transform)]
+pub struct CameraVolume {
+    pub half_extents: Vec3,
+    pub camera: Entity,
+}
+
+impl CameraVolume {
+    pub fn contains(&self, volume_transform: &GlobalTransform, point: Vec3) -> bool {
+        let local = volume_transform.affine().inverse().transform_point3(point);
+        local.abs().cmple(self.half_extents).all()
+    }
+}
+
+#[derive(Component, Default)]
+pub struct ActiveFixedCamera(pub Option<Entity>);
+
+pub fn toggle_fixed(
+    mut commands: Commands,
+    actions: ActionInput,
+    mut query: Query<(Entity, &mut CameraRig, Option<&InputSource>)>,
+) {
+    for (pivot, mut rig, source) in query.iter_mut() {
+        if !actions.just_pressed_by(CameraAction::ToggleFixed, source) {
+            continue;
+        }
+        if rig.mode == CameraMode::Fixed {
+            rig.mode = CameraMode::Orbit;
+        } else {
+            commands.entity(pivot).insert(ActiveFixedCamera::default());
+            rig.mode = CameraMode::Fixed;
+        }
+    }
+}
+
+pub fn update_fixed_camera(
+    players: Query<(&Player, &GlobalTransform)>,
+    volumes: Query<(&CameraVolume, &GlobalTransform)>,
+    viewpoints: Query<&GlobalTransform, With<FixedCamera>>,
+    mut rigs: Query<(Entity, &CameraRig, &mut ActiveFixedCamera, &mut CameraModeState)>,
+) {
+    for (pivot, rig, mut active, mut state) in rigs.iter_mut() {
+        if rig.mode != CameraMode::Fixed {
+            continue;
+        }
+        let Some(player) = rig_player(players.iter(), pivot) else { continue };
+        let entered = volumes
+            .iter()
+            .find(|(volume, transform)| volume.contains(transform, player.translation()))
+            .map(|(volume, _)| volume.camera);
+        if entered.is_some() && active.0 != entered {
+            active.0 = entered;
+        }
+
+        if let Some(viewpoint) = active.0.and_then(|entity| viewpoints.get(entity).ok()) {
+            state.desired = viewpoint.compute_transform();
+        }
+    }
+}
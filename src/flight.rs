@@ -0,0 +1,109 @@
+use bevy::prelude::*;
+
+use crate::{
+    input_map::{
+        ActionInput,
+        CameraAction,
+    },
+    mode::{
+        CameraMode,
+        CameraModeState,
+    },
+    player::{
+        Player,
+        rig_player,
+    },
+    rig::{
+        CameraRig,
+        RollSource,
+    },
+    split_screen::InputSource,
+};
+
+/// Follows a target through all six degrees of freedom, for flight prototypes.
+#[derive(Component)]
+pub struct FlightCamera {
+    pub target: Entity,
+    /// Camera position in the target's level frame.
+    pub offset: Vec3,
+    pub stiffness: f32,
+    pub rotation_stiffness: f32,
+}
+
+impl FlightCamera {
+    pub fn new(target: Entity) -> Self {
+        Self {
+            target,
+            offset: Vec3::new(0., 2., 8.),
+            stiffness: 10.,
+            rotation_stiffness: 8.,
+        }
+    }
+}
+
+/// Roll of `rotation` about its own forward axis, relative to the world horizon.
+pub fn bank_angle(rotation: Quat) -> f32 {
+    let forward = rotation * Vec3::NEG_Z;
+    let level = Transform::IDENTITY.looking_to(forward, Vec3::Y).rotation;
+    let up = level.inverse() * rotation * Vec3::Y;
+    (-up.x).atan2(up.y)
+}
+
+pub fn toggle_flight(
+    mut commands: Commands,
+    actions: ActionInput,
+    players: Query<(&Player, Entity)>,
+    mut query: Query<(Entity, &mut CameraRig, Option<&InputSource>)>,
+) {
+    for (pivot, mut rig, source) in query.iter_mut() {
+        if !actions.just_pressed_by(CameraAction::ToggleFlight, source) {
+            continue;
+        }
+        if rig.mode == CameraMode::Flight {
+            rig.mode = CameraMode::Orbit;
+        } else {
+            let Some(player) = rig_player(players.iter(), pivot) else { continue };
+            commands.entity(pivot).insert(FlightCamera::new(player));
+            rig.mode = CameraMode::Flight;
+        }
+    }
+}
+
+pub fn update_flight(
+    time: Res<Time>,
+    targets: Query<&GlobalTransform>,
+    mut rigs: Query<(&mut CameraRig, &FlightCamera, &mut CameraModeState)>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for (mut rig, flight, mut state) in rigs.iter_mut() {
+        if rig.mode != CameraMode::Flight {
+            continue;
+        }
+
+        let Ok(target) = targets.get(flight.target) else {
+            continue;
+        };
+        let (_, target_rotation, target_translation) = target.to_scale_rotation_translation();
+
+        let bank = bank_angle(target_rotation);
+        if rig.roll_source == RollSource::Target {
+            rig.roll = bank;
+        }
+
+        let level = target_rotation * Quat::from_rotation_z(-bank);
+        let goal = Transform::from_translation(target_translation + level * flight.offset)
+            .with_rotation(level * Quat::from_rotation_z(rig.roll));
+
+        if state.mode() != CameraMode::Flight {
+            state.desired = goal;
+            continue;
+        }
+
+        let position_blend = 1. - (-flight.stiffness * delta_seconds).exp();
+        let rotation_blend = 1. - (-flight.rotation_stiffness * delta_seconds).exp();
+        let translation = state.desired.translation.lerp(goal.translation, position_blend);
+        let rotation = state.desired.rotation.slerp(goal.rotation, rotation_blend);
+        state.desired = Transform::from_translation(translation).with_rotation(rotation);
+    }
+}
@@ -0,0 +1,97 @@
+use bevy::prelude::*;
+
+use crate::{
+    cursor::CursorLook,
+    input::MouseDeltas,
+    input_map::{
+        ActionInput,
+        CameraAction,
+        ModalActions,
+    },
+    mode::{
+        CameraMode,
+        CameraModeState,
+    },
+    rig::CameraRig,
+    settings::CameraSettings,
+    split_screen::{
+        InputSource,
+        accepts_keyboard_mouse,
+    },
+};
+
+pub fn toggle_free_fly(
+    mut modal: ResMut<ModalActions>,
+    mut query: Query<(&mut CameraRig, Option<&InputSource>)>,
+) {
+    let action = CameraAction::ToggleFreeFly;
+    for (mut rig, source) in query.iter_mut() {
+        if modal.just_activated_for(action, source) {
+            rig.mode = CameraMode::FreeFly;
+        } else if modal.just_deactivated_for(action, source) && rig.mode == CameraMode::FreeFly {
+            rig.mode = CameraMode::Orbit;
+        }
+    }
+
+    for (_, source) in query.iter() {
+        let flying = query.iter().any(|(rig, other)| other == source && rig.mode == CameraMode::FreeFly);
+        if modal.active_for(action, source) && !flying {
+            modal.set_for(action, source, false);
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn update_free_fly(
+    time: Res<Time>,
+    mut settings: ResMut<CameraSettings>,
+    actions: ActionInput,
+    cursor: Res<CursorLook>,
+    deltas: Res<MouseDeltas>,
+    projections: Query<&Projection>,
+    mut rigs: Query<(&CameraRig, &mut CameraModeState, Option<&InputSource>)>,
+) {
+    let mut scroll = 0.;
+    let delta_seconds = time.delta_secs();
+
+    for (rig, mut state, source) in rigs.iter_mut() {
+        if rig.mode != CameraMode::FreeFly {
+            continue;
+        }
+        if state.mode() != CameraMode::FreeFly {
+            state.desired = state.current();
+        }
+        let transform = &mut state.desired;
+
+        let mouse = accepts_keyboard_mouse(source);
+        let look = if mouse && cursor.active { deltas.motion } else { Vec2::ZERO };
+        if mouse && actions.pressed_by(CameraAction::ScrollSpeedModifier, source) {
+            scroll = deltas.scroll;
+        }
+
+        let mut movement = Vec3::ZERO;
+        if actions.pressed_by(CameraAction::MoveForward, source) { movement.z += 1.; }
+        if actions.pressed_by(CameraAction::MoveBack, source) { movement.z -= 1.; }
+        if actions.pressed_by(CameraAction::MoveRight, source) { movement.x += 1.; }
+        if actions.pressed_by(CameraAction::MoveLeft, source) { movement.x -= 1.; }
+        if actions.pressed_by(CameraAction::MoveUp, source) { movement.y += 1.; }
+        if actions.pressed_by(CameraAction::MoveDown, source) { movement.y -= 1.; }
+
+        let sensitivity = rig.look_sensitivity * rig.look_scale(&settings, &projections);
+        let (yaw, pitch, _) = transform.rotation.to_euler(EulerRot::YXZ);
+        let yaw = yaw - look.x * sensitivity;
+        let pitch = (pitch - look.y * sensitivity)
+            .clamp(-89f32.to_radians(), 89f32.to_radians());
+        transform.rotation = Quat::from_euler(EulerRot::YXZ, yaw, pitch, 0.);
+
+        let fwd = *transform.forward() * movement.z;
+        let right = *transform.right() * movement.x;
+        let up = Vec3::Y * movement.y;
+        transform.translation += (fwd + right + up).normalize_or_zero() * settings.fly_speed * delta_seconds;
+    }
+
+    if scroll != 0. {
+        settings.fly_speed = (settings.fly_speed * settings.scroll_speed_step.powf(scroll))
+            .clamp(settings.min_fly_speed, settings.max_fly_speed);
+    }
+}
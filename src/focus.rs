@@ -0,0 +1,143 @@
+use bevy::{
+    prelude::*,
+    math::bounding::{
+        BoundingSphere,
+        RayCast3d,
+    },
+    render::primitives::Aabb,
+    window::PrimaryWindow,
+};
+
+use crate::{
+    cursor::cursor_ray,
+    input_map::{
+        ActionInput,
+        CameraAction,
+    },
+    mode::CameraMode,
+    rig::CameraRig,
+    settings::CameraSettings,
+    turntable::bounding_sphere,
+};
+
+/// Marks an entity the camera can focus on by double-clicking it.
+#[derive(Component, Default)]
+pub struct Focusable;
+
+/// Eases the rig's orbit focus onto a point, or onto an entity it keeps tracking.
+#[derive(Component)]
+pub struct FocusTarget {
+    pub entity: Option<Entity>,
+    pub point: Vec3,
+    /// How quickly the focus closes the gap, per second.
+    pub stiffness: f32,
+}
+
+impl FocusTarget {
+    pub fn entity(entity: Entity) -> Self {
+        Self {
+            entity: Some(entity),
+            point: Vec3::ZERO,
+            stiffness: 6.,
+        }
+    }
+
+    pub fn point(point: Vec3) -> Self {
+        Self {
+            entity: None,
+            point,
+            stiffness: 6.,
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn pick_focus(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<CameraSettings>,
+    actions: ActionInput,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    focusables: Query<(Entity, &Aabb, &GlobalTransform), With<Focusable>>,
+    rigs: Query<(Entity, &CameraRig)>,
+    mut last_click: Local<Option<f32>>,
+) {
+    if !actions.just_pressed(CameraAction::Focus) {
+        return;
+    }
+
+    let now = time.elapsed_secs();
+    let double_click = last_click.is_some_and(|last| now - last <= settings.double_click_time);
+    *last_click = if double_click { None } else { Some(now) };
+    if !double_click {
+        return;
+    }
+
+    let Ok(window) = windows.get_single() else { return };
+
+    for (pivot, rig) in rigs.iter() {
+        if !rig.mode.follows_pivot() || rig.mode == CameraMode::FirstPerson {
+            continue;
+        }
+        let Some((camera, camera_transform)) = rig.camera_entity.and_then(|camera| cameras.get(camera).ok()) else {
+            continue;
+        };
+        let Some(ray) = cursor_ray(window, camera, camera_transform) else { continue };
+        let cast = RayCast3d::from_ray(ray, f32::MAX);
+
+        let hit = focusables
+            .iter()
+            .filter_map(|(entity, aabb, transform)| {
+                let (center, radius) = bounding_sphere(aabb, transform);
+                let distance = cast.sphere_intersection_at(&BoundingSphere::new(center, radius))?;
+                Some((entity, distance))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+
+        if let Some((entity, _)) = hit {
+            commands.entity(pivot).insert(FocusTarget::entity(entity));
+        }
+    }
+}
+
+pub fn update_focus(
+    mut commands: Commands,
+    time: Res<Time>,
+    actions: ActionInput,
+    targets: Query<(&Aabb, &GlobalTransform)>,
+    positions: Query<&GlobalTransform>,
+    mut rigs: Query<(Entity, &mut CameraRig, &Transform, &FocusTarget)>,
+) {
+    let recenter = actions.just_pressed(CameraAction::Recenter);
+
+    for (pivot, mut rig, transform, focus) in rigs.iter_mut() {
+        if recenter {
+            commands.entity(pivot).remove::<FocusTarget>();
+            continue;
+        }
+
+        let point = match focus.entity {
+            Some(entity) => match targets.get(entity) {
+                Ok((aabb, target)) => bounding_sphere(aabb, target).0,
+                Err(_) => match positions.get(entity) {
+                    Ok(target) => target.translation(),
+                    Err(_) => {
+                        commands.entity(pivot).remove::<FocusTarget>();
+                        continue;
+                    }
+                },
+            },
+            None => focus.point,
+        };
+
+        let offset = point - transform.translation;
+        let blend = 1. - (-focus.stiffness * time.delta_secs()).exp();
+        rig.focus_offset = rig.focus_offset.lerp(offset, blend);
+
+        if focus.entity.is_none() && rig.focus_offset.distance_squared(offset) < 1e-6 {
+            rig.focus_offset = offset;
+            commands.entity(pivot).remove::<FocusTarget>();
+        }
+    }
+}
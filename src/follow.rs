@@ -0,0 +1,330 @@
+use bevy::prelude::*;
+
+use crate::{
+    angle::angle_delta,
+    input::MouseDeltas,
+    input_map::{
+        ActionInput,
+        CameraAction,
+    },
+    mode::{
+        CameraMode,
+        CameraModeState,
+    },
+    player::{
+        Player,
+        rig_velocity,
+    },
+    rig::CameraRig,
+    split_screen::{
+        InputSource,
+        accepts_keyboard_mouse,
+    },
+};
+
+#[cfg(feature = "gamepad")]
+use crate::gamepad::GamepadAxes;
+
+/// Whether the pivot is standing on something; set by the movement code.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Grounded(pub bool);
+
+/// Lets the orbit centre trail the pivot instead of tracking it rigidly.
+#[derive(Component)]
+pub struct CameraFollow {
+    /// Half-size of the view-space box the pivot can move in without shifting the view, in world units.
+    pub dead_zone: Vec2,
+    /// How quickly the view catches up across the ground plane once the pivot leaves the dead zone,
+    /// per second; 0 snaps.
+    pub horizontal_stiffness: f32,
+    /// The same for height, kept separate so jumps can be softened without loosening horizontal tracking.
+    pub vertical_stiffness: f32,
+    /// Hold the view's height while the pivot's `Grounded` is false, platformer style.
+    pub hold_height_airborne: bool,
+    /// Vertical stiffness used to re-frame after landing.
+    pub landing_stiffness: f32,
+
+    /// Seconds of horizontal velocity the view leads the pivot by; 0 disables look-ahead.
+    pub look_ahead: f32,
+    pub max_lead: f32,
+    /// How quickly the lead follows changes in velocity, per second.
+    pub lead_stiffness: f32,
+
+    /// Seconds ahead to extrapolate the pivot before following it, hiding smoothing lag on fast
+    /// targets; 0 follows the pivot itself.
+    pub prediction: f32,
+    /// Time constant the velocity estimate is averaged over, in seconds.
+    pub velocity_window: f32,
+
+    /// Point the camera currently orbits; reset to the pivot when `None`.
+    pub point: Option<Vec3>,
+    pub lead: Vec3,
+    /// Smoothed pivot velocity.
+    pub velocity: Vec3,
+    pub last_target: Option<Vec3>,
+    /// Catching up vertically after a landing.
+    pub reframing: bool,
+}
+
+impl Default for CameraFollow {
+    fn default() -> Self {
+        Self {
+            dead_zone: Vec2::new(1., 0.5),
+            horizontal_stiffness: 8.,
+            vertical_stiffness: 8.,
+            hold_height_airborne: false,
+            landing_stiffness: 15.,
+
+            look_ahead: 0.,
+            max_lead: 3.,
+            lead_stiffness: 3.,
+
+            prediction: 0.,
+            velocity_window: 0.15,
+
+            point: None,
+            lead: Vec3::ZERO,
+            velocity: Vec3::ZERO,
+            last_target: None,
+            reframing: false,
+        }
+    }
+}
+
+impl CameraFollow {
+    /// Folds the pivot's latest move into the smoothed velocity.
+    pub fn update_velocity(&mut self, target: Vec3, delta_seconds: f32) -> Vec3 {
+        let velocity = match self.last_target {
+            Some(last) if delta_seconds > 0. => (target - last) / delta_seconds,
+            _ => Vec3::ZERO,
+        };
+        self.last_target = Some(target);
+
+        let blend = if self.velocity_window > 0. { 1. - (-delta_seconds / self.velocity_window).exp() } else { 1. };
+        self.velocity = self.velocity.lerp(velocity, blend);
+        self.velocity
+    }
+
+    /// Offset ahead of the pivot along its smoothed velocity.
+    pub fn update_lead(&mut self, delta_seconds: f32) -> Vec3 {
+        let goal = (self.velocity.with_y(0.) * self.look_ahead).clamp_length_max(self.max_lead);
+        let blend = 1. - (-self.lead_stiffness * delta_seconds).exp();
+        self.lead = self.lead.lerp(goal, blend);
+        self.lead
+    }
+
+    /// Moves `point` towards `target` by however far `target` sits outside the dead zone.
+    pub fn track(&mut self, target: Vec3, view: Quat, grounded: bool, delta_seconds: f32) -> Vec3 {
+        let point = self.point.unwrap_or(target);
+
+        let local = view.inverse() * (target - point);
+        let outside = |offset: f32, half: f32| offset - offset.clamp(-half, half);
+        let excess = Vec3::new(
+            outside(local.x, self.dead_zone.x),
+            outside(local.y, self.dead_zone.y),
+            local.z,
+        );
+
+        let mut goal = point + view * excess;
+
+        let mut vertical_stiffness = self.vertical_stiffness;
+        if self.hold_height_airborne {
+            if !grounded {
+                goal.y = point.y;
+                self.reframing = true;
+            } else if self.reframing {
+                vertical_stiffness = self.landing_stiffness;
+                self.reframing = (goal.y - point.y).abs() > 0.01;
+            }
+        }
+
+        let blend = |stiffness: f32| if stiffness > 0. { 1. - (-stiffness * delta_seconds).exp() } else { 1. };
+        let horizontal = blend(self.horizontal_stiffness);
+        let point = Vec3::new(
+            point.x.lerp(goal.x, horizontal),
+            point.y.lerp(goal.y, blend(vertical_stiffness)),
+            point.z.lerp(goal.z, horizontal),
+        );
+        self.point = Some(point);
+        point
+    }
+}
+
+pub fn update_follow(
+    time: Res<Time>,
+    mut rigs: Query<(&CameraRig, &Transform, &mut CameraFollow, &mut CameraModeState, Option<&Grounded>)>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for (rig, pivot, mut follow, mut state, grounded) in rigs.iter_mut() {
+        if !rig.mode.follows_pivot() || rig.mode == CameraMode::FirstPerson {
+            follow.point = None;
+            follow.lead = Vec3::ZERO;
+            follow.velocity = Vec3::ZERO;
+            follow.last_target = None;
+            continue;
+        }
+
+        let velocity = follow.update_velocity(pivot.translation, delta_seconds);
+        let lead = follow.update_lead(delta_seconds);
+        let predicted = pivot.translation + velocity * follow.prediction;
+
+        let grounded = grounded.is_none_or(|grounded| grounded.0);
+        let point = follow.track(predicted + lead, state.desired.rotation, grounded, delta_seconds);
+        state.desired.translation += point - pivot.translation;
+    }
+}
+
+/// Swings the camera back behind the pivot's direction of travel once look input stops.
+#[derive(Component)]
+pub struct AutoRecenter {
+    /// Seconds without look input before recentering starts.
+    pub delay: f32,
+    /// Turn rate while recentering, in radians per second.
+    pub speed: f32,
+    /// Only recenter when `CameraAction::RecenterBehind` is pressed.
+    pub on_key_only: bool,
+
+    pub idle_for: f32,
+    /// Yaw of the last direction of travel.
+    pub heading: Option<f32>,
+    /// A key-triggered recenter still in progress.
+    pub requested: bool,
+}
+
+impl Default for AutoRecenter {
+    fn default() -> Self {
+        Self {
+            delay: 1.5,
+            speed: 120f32.to_radians(),
+            on_key_only: false,
+
+            idle_for: 0.,
+            heading: None,
+            requested: false,
+        }
+    }
+}
+
+pub fn auto_recenter(
+    time: Res<Time>,
+    actions: ActionInput,
+    deltas: Res<MouseDeltas>,
+    #[cfg(feature = "gamepad")]
+    gamepad: Res<GamepadAxes>,
+    players: Query<&Player>,
+    mut rigs: Query<(Entity, &mut CameraRig, &mut AutoRecenter, Option<&InputSource>)>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for (entity, mut rig, mut recenter, source) in rigs.iter_mut() {
+        let looking = accepts_keyboard_mouse(source) && deltas.motion != Vec2::ZERO
+            || actions.pressed_by(CameraAction::OrbitLeft, source)
+            || actions.pressed_by(CameraAction::OrbitRight, source);
+        #[cfg(feature = "gamepad")]
+        let looking = looking || gamepad.for_source(source).look != Vec2::ZERO;
+        let pressed = actions.just_pressed_by(CameraAction::RecenterBehind, source);
+
+        let velocity = rig_velocity(&players, entity).with_y(0.);
+        let moving = velocity.length_squared() > 1e-6;
+        if moving {
+            recenter.heading = Some(CameraRig::yaw_towards(velocity));
+        }
+
+        if !rig.mode.follows_pivot() || rig.mode == CameraMode::Isometric {
+            recenter.requested = false;
+            continue;
+        }
+
+        recenter.idle_for = if looking { 0. } else { recenter.idle_for + delta_seconds };
+        if pressed {
+            recenter.requested = true;
+        }
+        if looking {
+            recenter.requested = false;
+        }
+
+        let automatic = !recenter.on_key_only && moving && recenter.idle_for >= recenter.delay;
+        if !(automatic || recenter.requested) {
+            continue;
+        }
+        let Some(heading) = recenter.heading else { continue };
+
+        let turn = angle_delta(rig.yaw, heading);
+        let max_step = recenter.speed * delta_seconds;
+        rig.yaw += turn.clamp(-max_step, max_step);
+        if turn.abs() <= max_step {
+            recenter.requested = false;
+        }
+    }
+}
+
+/// Pulls the camera back while the pivot moves fast and eases it in again once it slows down.
+#[derive(Component)]
+pub struct SpeedZoom {
+    /// Speed above which the camera pulls back.
+    pub fast_speed: f32,
+    /// Speed below which it tightens in again; the gap to `fast_speed` keeps it from pumping.
+    pub slow_speed: f32,
+    /// Distance added on top of the player's zoom while fast.
+    pub extra_distance: f32,
+    /// How quickly the extra distance blends in and out, per second.
+    pub rate: f32,
+    /// Time constant the speed estimate is averaged over, in seconds.
+    pub velocity_window: f32,
+
+    pub fast: bool,
+    pub speed: f32,
+    /// Extra distance currently added to the rig.
+    pub applied: f32,
+}
+
+impl Default for SpeedZoom {
+    fn default() -> Self {
+        Self {
+            fast_speed: 12.,
+            slow_speed: 6.,
+            extra_distance: 6.,
+            rate: 1.5,
+            velocity_window: 0.3,
+
+            fast: false,
+            speed: 0.,
+            applied: 0.,
+        }
+    }
+}
+
+pub fn speed_zoom(
+    time: Res<Time>,
+    players: Query<&Player>,
+    mut rigs: Query<(Entity, &mut CameraRig, &mut SpeedZoom)>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for (entity, mut rig, mut zoom) in rigs.iter_mut() {
+        let speed = rig_velocity(&players, entity).length();
+
+        let blend = if zoom.velocity_window > 0. { 1. - (-delta_seconds / zoom.velocity_window).exp() } else { 1. };
+        zoom.speed = zoom.speed.lerp(speed, blend);
+
+        if zoom.speed > zoom.fast_speed {
+            zoom.fast = true;
+        } else if zoom.speed < zoom.slow_speed {
+            zoom.fast = false;
+        }
+
+        let goal = if zoom.fast && rig.mode.follows_pivot() && rig.mode != CameraMode::FirstPerson {
+            zoom.extra_distance
+        } else {
+            0.
+        };
+        let applied = zoom.applied.lerp(goal, 1. - (-zoom.rate * delta_seconds).exp());
+
+        // Shift by the change only, so wheel zoom made meanwhile is kept, and count only what the
+        // distance limits let through.
+        let before = rig.target_distance;
+        rig.target_distance = (before + applied - zoom.applied).clamp(rig.min_distance, rig.max_distance);
+        zoom.applied += rig.target_distance - before;
+    }
+}
@@ -0,0 +1,152 @@
+use bevy::{
+    prelude::*,
+    math::bounding::{
+        Aabb3d,
+        BoundingVolume,
+        RayCast3d,
+    },
+    render::primitives::Aabb,
+    window::PrimaryWindow,
+};
+
+use crate::{
+    cursor::cursor_ray,
+    focus::{
+        FocusTarget,
+        Focusable,
+    },
+    input_map::{
+        ActionInput,
+        CameraAction,
+    },
+    mode::{
+        CameraMode,
+        CameraModeState,
+    },
+    rig::CameraRig,
+    settings::CameraSettings,
+    turntable::Turntable,
+};
+
+/// Fits `entity`'s bounds, children included, into `rig`'s view with `CameraSettings::frame_margin`
+/// to spare.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct FrameEntity {
+    pub rig: Entity,
+    pub entity: Entity,
+}
+
+pub type BoundsQuery<'w, 's> = Query<'w, 's, (Option<&'static Aabb>, &'static GlobalTransform, Option<&'static Children>)>;
+
+/// World-space box around the mesh bounds of `entity` and its descendants.
+pub fn entity_bounds(entity: Entity, bounds: &BoundsQuery) -> Option<Aabb3d> {
+    let (aabb, transform, children) = bounds.get(entity).ok()?;
+
+    let own = aabb.map(|aabb| {
+        let (center, half) = (Vec3::from(aabb.center), Vec3::from(aabb.half_extents));
+        let corners = (0..8).map(|i| {
+            let sign = Vec3::new(
+                if i & 1 == 0 { -1. } else { 1. },
+                if i & 2 == 0 { -1. } else { 1. },
+                if i & 4 == 0 { -1. } else { 1. },
+            );
+            transform.transform_point(center + half * sign)
+        });
+        let (min, max) = corners.fold((Vec3::MAX, Vec3::MIN), |(min, max), corner| (min.min(corner), max.max(corner)));
+        Aabb3d { min: min.into(), max: max.into() }
+    });
+
+    children
+        .into_iter()
+        .flatten()
+        .filter_map(|&child| entity_bounds(child, bounds))
+        .fold(own, |merged, child| Some(merged.map_or(child, |merged| merged.merge(&child))))
+}
+
+/// Distance at which a sphere of `radius` fills a perspective view to within `margin`, whichever of
+/// its two field-of-view axes is narrower.
+pub fn fit_distance(radius: f32, fov: f32, aspect_ratio: f32, margin: f32) -> f32 {
+    let horizontal = ((fov / 2.).tan() * aspect_ratio).atan();
+    radius * margin / (fov / 2.).min(horizontal).sin()
+}
+
+/// Frames the `Focusable` under the cursor when `CameraAction::Frame` is pressed in free-fly.
+#[allow(clippy::too_many_arguments)]
+pub fn frame_hovered(
+    actions: ActionInput,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    focusables: Query<Entity, With<Focusable>>,
+    bounds: BoundsQuery,
+    rigs: Query<(Entity, &CameraRig)>,
+    mut frames: EventWriter<FrameEntity>,
+) {
+    if !actions.just_pressed(CameraAction::Frame) {
+        return;
+    }
+    let Ok(window) = windows.get_single() else { return };
+
+    for (pivot, rig) in rigs.iter() {
+        if rig.mode != CameraMode::FreeFly {
+            continue;
+        }
+        let Some((camera, camera_transform)) = rig.camera_entity.and_then(|camera| cameras.get(camera).ok()) else {
+            continue;
+        };
+        let Some(ray) = cursor_ray(window, camera, camera_transform) else { continue };
+        let cast = RayCast3d::from_ray(ray, f32::MAX);
+
+        let hit = focusables
+            .iter()
+            .filter_map(|entity| Some((entity, cast.aabb_intersection_at(&entity_bounds(entity, &bounds)?)?)))
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+
+        if let Some((entity, _)) = hit {
+            frames.send(FrameEntity { rig: pivot, entity });
+        }
+    }
+}
+
+pub fn frame_entities(
+    mut commands: Commands,
+    settings: Res<CameraSettings>,
+    mut events: EventReader<FrameEntity>,
+    bounds: BoundsQuery,
+    projections: Query<&Projection>,
+    mut rigs: Query<(&mut CameraRig, &mut CameraModeState, Option<&mut Turntable>)>,
+) {
+    for event in events.read() {
+        let Some(aabb) = entity_bounds(event.entity, &bounds) else { continue };
+        let Ok((mut rig, mut state, turntable)) = rigs.get_mut(event.rig) else { continue };
+
+        let (fov, aspect_ratio) = match rig.camera_entity.and_then(|camera| projections.get(camera).ok()) {
+            Some(Projection::Perspective(perspective)) => (perspective.fov, perspective.aspect_ratio),
+            _ => (std::f32::consts::FRAC_PI_4, 1.),
+        };
+        let center = Vec3::from(aabb.center());
+        let radius = Vec3::from(aabb.half_size()).length();
+        let distance = fit_distance(radius, fov, aspect_ratio, settings.frame_margin);
+
+        if rig.mode == CameraMode::FreeFly {
+            // Keep the heading and back straight away from the object along it.
+            let forward = state.desired.forward();
+            state.desired.translation = center - forward * distance;
+            continue;
+        }
+        if let Some(mut turntable) = turntable.filter(|_| rig.mode == CameraMode::Turntable) {
+            turntable.focus = center;
+            turntable.distance = distance.clamp(turntable.min_distance, turntable.max_distance);
+            continue;
+        }
+        if !rig.mode.follows_pivot() || rig.mode == CameraMode::FirstPerson {
+            continue;
+        }
+
+        let pitch = std::f32::consts::FRAC_PI_2 - settings.frame_elevation;
+        rig.camera_pitch = pitch.clamp(rig.min_pitch, rig.max_pitch);
+        let delta = distance - rig.target_distance;
+        rig.zoom_by(delta);
+        // Eases the orbit centre over rather than moving the pivot itself.
+        commands.entity(event.rig).insert(FocusTarget::point(center));
+    }
+}
@@ -0,0 +1,120 @@
+use bevy::{
+    prelude::*,
+    utils::HashMap,
+};
+
+use crate::{
+    input_map::{
+        CameraAction,
+        ModalActions,
+    },
+    mode::CameraMode,
+    rig::{
+        CameraRig,
+        ZoomMode,
+    },
+    settings::CameraSettings,
+    split_screen::InputSource,
+};
+
+/// Stick values of one gamepad, or of all of them together.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GamepadSticks {
+    pub look: Vec2,
+    pub movement: Vec2,
+    /// Positive zooms in.
+    pub zoom: f32,
+}
+
+/// Stick values read this frame, for systems that don't talk to gamepads directly.
+#[derive(Resource, Default)]
+pub struct GamepadAxes {
+    pub look: Vec2,
+    pub movement: Vec2,
+    /// Positive zooms in.
+    pub zoom: f32,
+    /// The same for each gamepad on its own.
+    pub devices: HashMap<Entity, GamepadSticks>,
+}
+
+impl GamepadAxes {
+    /// Sticks that drive a rig with `source`.
+    pub fn for_source(&self, source: Option<&InputSource>) -> GamepadSticks {
+        match source {
+            None => GamepadSticks {
+                look: self.look,
+                movement: self.movement,
+                zoom: self.zoom,
+            },
+            Some(InputSource::KeyboardMouse) => GamepadSticks::default(),
+            Some(InputSource::Gamepad(gamepad)) => self.devices.get(gamepad).copied().unwrap_or_default(),
+        }
+    }
+}
+
+pub fn process_gamepad_events(
+    time: Res<Time>,
+    settings: Res<CameraSettings>,
+    modal: Res<ModalActions>,
+    gamepads: Query<(Entity, &Gamepad)>,
+    mut axes: ResMut<GamepadAxes>,
+    projections: Query<&Projection>,
+    mut query: Query<(&mut CameraRig, Option<&InputSource>)>,
+) {
+    // Cleared rather than replaced, to keep the map's allocation.
+    axes.look = Vec2::ZERO;
+    axes.movement = Vec2::ZERO;
+    axes.zoom = 0.;
+    axes.devices.clear();
+    for (entity, gamepad) in gamepads.iter() {
+        let mut sticks = GamepadSticks {
+            look: settings.right_stick.apply(gamepad.right_stick()),
+            movement: settings.left_stick.apply(gamepad.left_stick()),
+            zoom: gamepad.get(GamepadButton::RightTrigger2).unwrap_or(0.)
+                - gamepad.get(GamepadButton::LeftTrigger2).unwrap_or(0.)
+                + gamepad.dpad().y,
+        };
+        axes.look += sticks.look;
+        axes.movement += sticks.movement;
+        axes.zoom += sticks.zoom;
+
+        sticks.movement = sticks.movement.clamp_length_max(1.);
+        axes.devices.insert(entity, sticks);
+    }
+    axes.movement = axes.movement.clamp_length_max(1.);
+
+    let delta_seconds = time.delta_secs();
+
+    for (mut rig, source) in query.iter_mut() {
+        if !rig.mode.follows_pivot() {
+            continue;
+        }
+        let sticks = axes.for_source(source);
+
+        if rig.mode != CameraMode::Isometric {
+            let sensitivity = settings.gamepad_look_sensitivity * rig.look_scale(&settings, &projections);
+            let step = sticks.look.x * delta_seconds * sensitivity;
+            if modal.active_for(CameraAction::FreeLook, source) {
+                rig.free_look_yaw += step;
+            } else {
+                rig.yaw += step;
+            }
+            rig.pitch_by(sticks.look.y * delta_seconds * sensitivity);
+        }
+        // The left trigger aims instead of zooming out while held.
+        if rig.aiming {
+            continue;
+        }
+        match rig.zoom_mode {
+            ZoomMode::Distance => {
+                let before = rig.target_distance;
+                rig.target_distance -= sticks.zoom * delta_seconds * settings.gamepad_zoom_speed;
+                rig.limit_zoom_input(before);
+            }
+            ZoomMode::FieldOfView(zoom) => {
+                let step = sticks.zoom * delta_seconds * zoom.key_speed;
+                rig.target_fov = rig.target_fov.map(|fov| (fov - step).clamp(zoom.min_fov, zoom.max_fov));
+            }
+        }
+    }
+}
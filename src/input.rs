@@ -0,0 +1,369 @@
+use bevy::{
+    prelude::*,
+    input::mouse::{
+        MouseMotion,
+        MouseScrollUnit,
+        MouseWheel,
+    },
+};
+
+use crate::{
+    cursor::CursorLook,
+    input_map::{
+        ActionInput,
+        CameraAction,
+        ModalActions,
+    },
+    mode::{
+        CameraMode,
+        CameraModeState,
+    },
+    rig::{
+        CameraRig,
+        RollSource,
+        ZoomMode,
+    },
+    settings::{
+        CameraSettings,
+        LookSmoothing,
+    },
+    split_screen::{
+        InputSource,
+        accepts_keyboard_mouse,
+    },
+};
+
+/// Mouse motion and wheel totals for the current frame, summed over every event.
+#[derive(Resource, Default)]
+pub struct MouseDeltas {
+    pub motion: Vec2,
+    /// Wheel notches, positive away from the user.
+    pub scroll: f32,
+}
+
+pub fn accumulate_mouse_deltas(
+    settings: Res<CameraSettings>,
+    actions: ActionInput,
+    mut mouse_motion_events: EventReader<MouseMotion>,
+    mut mouse_wheel_events: EventReader<MouseWheel>,
+    mut deltas: ResMut<MouseDeltas>,
+) {
+    deltas.motion = mouse_motion_events.read().map(|event| event.delta).sum();
+    deltas.scroll = mouse_wheel_events
+        .read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y,
+            MouseScrollUnit::Pixel => event.y / settings.scroll_pixels_per_notch,
+        })
+        .sum();
+
+    if let Some(axis) = actions.look_axis() {
+        deltas.motion = axis;
+    }
+    if let Some(axis) = actions.zoom_axis() {
+        deltas.scroll = axis;
+    }
+}
+
+/// Running state of the `LookSmoothing` filter.
+#[derive(Default)]
+pub struct LookFilter {
+    level: Vec2,
+    trend: Vec2,
+}
+
+impl LookFilter {
+    pub fn apply(&mut self, smoothing: LookSmoothing, raw: Vec2, delta_seconds: f32) -> Vec2 {
+        match smoothing {
+            LookSmoothing::None => {
+                self.level = raw;
+                self.trend = Vec2::ZERO;
+            }
+            LookSmoothing::Exponential { rate } => {
+                let alpha = 1. - (-rate * delta_seconds).exp();
+                self.level = self.level.lerp(raw, alpha);
+            }
+            LookSmoothing::DoubleExponential { rate, trend_rate } => {
+                let alpha = 1. - (-rate * delta_seconds).exp();
+                let beta = 1. - (-trend_rate * delta_seconds).exp();
+                let previous = self.level;
+                self.level = (self.level + self.trend).lerp(raw, alpha);
+                self.trend = self.trend.lerp(self.level - previous, beta);
+            }
+        }
+        self.level
+    }
+}
+
+/// Keeps an orbit spinning after the drag that started it ends.
+#[derive(Component)]
+pub struct OrbitInertia {
+    /// Rate the spin decays at, per second.
+    pub friction: f32,
+    /// Cap on the carried spin, in radians per second.
+    pub max_angular_velocity: f32,
+    /// Current yaw and pitch spin, in radians per second.
+    pub velocity: Vec2,
+}
+
+impl Default for OrbitInertia {
+    fn default() -> Self {
+        Self {
+            friction: 4.,
+            max_angular_velocity: 360f32.to_radians(),
+            velocity: Vec2::ZERO,
+        }
+    }
+}
+
+impl OrbitInertia {
+    /// Passes a frame's yaw/pitch step through, remembering its speed, or coasts when there is none.
+    pub fn step(&mut self, input: Vec2, delta_seconds: f32) -> Vec2 {
+        if delta_seconds <= 0. {
+            return input;
+        }
+        if input != Vec2::ZERO {
+            self.velocity = (input / delta_seconds).clamp_length_max(self.max_angular_velocity);
+            return input;
+        }
+
+        self.velocity *= (-self.friction * delta_seconds).exp();
+        if self.velocity.length_squared() < 1e-6 {
+            self.velocity = Vec2::ZERO;
+        }
+        self.velocity * delta_seconds
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn process_mouse_events(
+    time: Res<Time>,
+    settings: Res<CameraSettings>,
+    cursor: Res<CursorLook>,
+    actions: ActionInput,
+    modal: Res<ModalActions>,
+    deltas: Res<MouseDeltas>,
+    projections: Query<&Projection>,
+    mut filter: Local<LookFilter>,
+    mut query: Query<(&mut CameraRig, Option<&mut OrbitInertia>, Option<&InputSource>)>,
+) {
+    let mut look = deltas.motion;
+    if !cursor.active || actions.pressed(CameraAction::PanPivot) {
+        look = Vec2::ZERO;
+    }
+    look *= settings.mouse_sensitivity;
+    if settings.invert_x { look.x = -look.x; }
+    if settings.invert_y { look.y = -look.y; }
+    look = filter.apply(settings.look_smoothing, look, time.delta_secs());
+
+    let mut wheel = deltas.scroll;
+    if settings.invert_zoom {
+        wheel = -wheel;
+    }
+
+    let delta_seconds = time.delta_secs();
+    let key_zoom = actions.axis(CameraAction::ZoomOut, CameraAction::ZoomIn);
+    let mut key_steps = 0.;
+    if actions.just_pressed(CameraAction::ZoomIn) { key_steps += 1.; }
+    if actions.just_pressed(CameraAction::ZoomOut) { key_steps -= 1.; }
+
+    let key_orbit = Vec2::new(
+        actions.axis(CameraAction::OrbitLeft, CameraAction::OrbitRight),
+        actions.axis(CameraAction::OrbitDown, CameraAction::OrbitUp),
+    ) * settings.key_orbit_speed * delta_seconds;
+
+    for (mut rig, inertia, source) in query.iter_mut() {
+        if !rig.mode.follows_pivot() || !accepts_keyboard_mouse(source) {
+            continue;
+        }
+
+        // A held modifier hands the wheel to the free-fly speed or the field of view instead.
+        let zoom_delta = if actions.scroll_modified_by(source) { 0. } else { wheel };
+
+        let free_look = modal.active_for(CameraAction::FreeLook, source);
+        if rig.mode != CameraMode::Isometric {
+            let sensitivity = rig.look_sensitivity * rig.look_scale(&settings, &projections);
+            let mut step = Vec2::new(look.x * sensitivity + key_orbit.x, look.y * sensitivity + key_orbit.y);
+            if let Some(mut inertia) = inertia {
+                step = inertia.step(step, delta_seconds);
+            }
+
+            if free_look {
+                rig.free_look_yaw += step.x;
+            } else {
+                rig.yaw += step.x;
+            }
+            rig.pitch_by(-step.y);
+        }
+
+        let zooming = zoom_delta + key_zoom;
+        let before = rig.target_distance;
+        match (rig.zoom_mode, rig.mode) {
+            (ZoomMode::FieldOfView(zoom), _) => {
+                if let Some(fov) = rig.target_fov {
+                    let step = zoom_delta * zoom.step + key_zoom * zoom.key_speed * delta_seconds;
+                    rig.target_fov = Some((fov - step).clamp(zoom.min_fov, zoom.max_fov));
+                }
+            }
+            (_, CameraMode::FirstPerson) => {
+                if zooming < 0. {
+                    rig.mode = CameraMode::Orbit;
+                    if let Some(&nearest) = rig.zoom_presets.first() {
+                        let step = nearest - rig.target_distance;
+                        rig.zoom_by(step);
+                    }
+                }
+            }
+            (_, mode) if !rig.zoom_presets.is_empty() => {
+                // Whole notches step one preset each; smooth scrolling builds up to them.
+                rig.preset_scroll += zoom_delta + key_steps;
+                let steps = rig.preset_scroll.trunc();
+                rig.preset_scroll -= steps;
+
+                if let Some(nearest) = rig.nearest_zoom_preset().filter(|_| steps != 0.) {
+                    let index = nearest as i32 - steps as i32;
+                    if index < 0 && mode == CameraMode::Orbit {
+                        rig.mode = CameraMode::FirstPerson;
+                    } else {
+                        let index = index.clamp(0, rig.zoom_presets.len() as i32 - 1) as usize;
+                        let step = rig.zoom_presets[index] - rig.target_distance;
+                        rig.zoom_by(step);
+                    }
+                }
+            }
+            (_, CameraMode::Orbit) if zooming > 0. && rig.camera_distance <= rig.min_distance => {
+                let distance = rig.min_distance;
+                rig.set_distance(distance);
+                rig.mode = CameraMode::FirstPerson;
+            }
+            _ => {
+                if zoom_delta != 0. {
+                    let step = -zoom_delta * rig.zoom_sensitivity;
+                    rig.zoom_by(step);
+                }
+                rig.target_distance -= key_zoom * settings.key_zoom_speed * delta_seconds;
+            }
+        }
+        rig.limit_zoom_input(before);
+        rig.advance_zoom(settings.zoom_smoothing, delta_seconds);
+    }
+}
+
+pub fn pan_pivot(
+    settings: Res<CameraSettings>,
+    actions: ActionInput,
+    deltas: Res<MouseDeltas>,
+    mut query: Query<(&mut CameraRig, &CameraModeState, Option<&InputSource>)>,
+) {
+    let drag = deltas.motion;
+
+    for (mut rig, state, source) in query.iter_mut() {
+        if !rig.mode.follows_pivot() || rig.mode == CameraMode::FirstPerson {
+            continue;
+        }
+
+        if actions.just_pressed_by(CameraAction::Recenter, source) {
+            rig.focus_offset = Vec3::ZERO;
+        }
+
+        let panning = accepts_keyboard_mouse(source) && actions.pressed_by(CameraAction::PanPivot, source);
+        if panning && drag != Vec2::ZERO {
+            let right = state.desired.rotation * Vec3::X;
+            let up = state.desired.rotation * Vec3::Y;
+            let scale = settings.pan_sensitivity * rig.camera_distance;
+            rig.focus_offset += (-right * drag.x + up * drag.y) * scale;
+        }
+    }
+}
+
+pub fn scroll_fov(
+    settings: Res<CameraSettings>,
+    actions: ActionInput,
+    deltas: Res<MouseDeltas>,
+    mut rigs: Query<(&mut CameraRig, Option<&InputSource>)>,
+) {
+    let scroll = deltas.scroll;
+    if scroll == 0. {
+        return;
+    }
+
+    // Edit the lens rather than the projection, so effects layered on top keep this as their base.
+    for (mut rig, source) in rigs.iter_mut() {
+        if !accepts_keyboard_mouse(source) || !actions.pressed_by(CameraAction::ScrollFovModifier, source) {
+            continue;
+        }
+        rig.lens.fov = (rig.lens.fov - scroll * settings.fov_scroll_step).clamp(settings.min_fov, settings.max_fov);
+    }
+}
+
+pub fn toggle_first_person(
+    mut modal: ResMut<ModalActions>,
+    mut query: Query<(&mut CameraRig, Option<&InputSource>)>,
+) {
+    let action = CameraAction::ToggleFirstPerson;
+    for (mut rig, source) in query.iter_mut() {
+        if modal.just_activated_for(action, source) && rig.mode == CameraMode::Orbit {
+            rig.mode = CameraMode::FirstPerson;
+        } else if modal.just_deactivated_for(action, source) && rig.mode == CameraMode::FirstPerson {
+            rig.mode = CameraMode::Orbit;
+        }
+    }
+
+    for (_, source) in query.iter() {
+        let entered = query
+            .iter()
+            .any(|(rig, other)| other == source && rig.mode == CameraMode::FirstPerson);
+        if modal.active_for(action, source) && !entered {
+            // Zoomed or switched out of first person, so the next press enters it again.
+            modal.set_for(action, source, false);
+        }
+    }
+}
+
+pub fn update_aim(
+    modal: Res<ModalActions>,
+    mut query: Query<(&mut CameraRig, Option<&InputSource>)>,
+) {
+    for (mut rig, source) in query.iter_mut() {
+        // The turntable pans with the aim button by default.
+        rig.aiming = modal.active_for(CameraAction::Aim, source) && rig.mode != CameraMode::Turntable;
+    }
+}
+
+pub fn swap_shoulder(
+    actions: ActionInput,
+    mut query: Query<(&mut CameraRig, Option<&InputSource>)>,
+) {
+    for (mut rig, source) in query.iter_mut() {
+        if actions.just_pressed_by(CameraAction::SwapShoulder, source) {
+            rig.left_shoulder = !rig.left_shoulder;
+        }
+    }
+}
+
+pub fn roll_camera(
+    time: Res<Time>,
+    actions: ActionInput,
+    mut query: Query<(&mut CameraRig, Option<&InputSource>)>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for (mut rig, source) in query.iter_mut() {
+        if rig.roll_source != RollSource::Keys {
+            continue;
+        }
+        if rig.mode == CameraMode::Isometric || !(rig.mode.follows_pivot() || rig.mode == CameraMode::Flight) {
+            continue;
+        }
+
+        let mut input = 0.;
+        if actions.pressed_by(CameraAction::RollLeft, source) { input += 1.; }
+        if actions.pressed_by(CameraAction::RollRight, source) { input -= 1.; }
+
+        if input != 0. {
+            rig.roll += input * rig.roll_speed * delta_seconds;
+        } else if rig.auto_level {
+            rig.roll *= (-rig.level_rate * delta_seconds).exp();
+        }
+    }
+}
@@ -0,0 +1,512 @@
+use bevy::{
+    prelude::*,
+    ecs::system::SystemParam,
+    utils::{
+        HashMap,
+        HashSet,
+    },
+};
+
+use crate::{
+    settings::{
+        Activation,
+        CameraSettings,
+        LookMode,
+    },
+    split_screen::{
+        InputSource,
+        accepts_keyboard_mouse,
+    },
+};
+#[cfg(feature = "gamepad")]
+use crate::split_screen::accepts_gamepad;
+
+/// Bookmark slot keys, in slot order.
+pub const BOOKMARK_ACTIONS: [CameraAction; 9] = [
+    CameraAction::Bookmark1,
+    CameraAction::Bookmark2,
+    CameraAction::Bookmark3,
+    CameraAction::Bookmark4,
+    CameraAction::Bookmark5,
+    CameraAction::Bookmark6,
+    CameraAction::Bookmark7,
+    CameraAction::Bookmark8,
+    CameraAction::Bookmark9,
+];
+
+/// Actions that switch a camera state on and off, each held or toggled per `CameraSettings::modal_activation`.
+pub const MODAL_ACTIONS: [CameraAction; 4] = [
+    CameraAction::FreeLook,
+    CameraAction::Aim,
+    CameraAction::ToggleFirstPerson,
+    CameraAction::ToggleFreeFly,
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "leafwing", derive(Reflect, leafwing_input_manager::Actionlike))]
+pub enum CameraAction {
+    MoveForward,
+    MoveBack,
+    MoveLeft,
+    MoveRight,
+    MoveUp,
+    MoveDown,
+    /// Turns the top-down and isometric views.
+    RotateLeft,
+    RotateRight,
+    RollLeft,
+    RollRight,
+    /// Orbit the pivot-following modes from the keyboard, alongside mouse look. Unbound by
+    /// default, as the nearby keys already move, turn, roll and frame.
+    OrbitLeft,
+    OrbitRight,
+    OrbitUp,
+    OrbitDown,
+    /// Held to orbit with the mouse in the turntable view.
+    OrbitModifier,
+    /// Held to pan with the mouse in the turntable view.
+    PanModifier,
+    ZoomIn,
+    ZoomOut,
+    /// Held to make the scroll wheel change move or fly speed instead of distance.
+    ScrollSpeedModifier,
+    /// Held to make the scroll wheel change the field of view instead of distance.
+    ScrollFovModifier,
+    /// Held to look around under `LookMode::HoldToLook`.
+    Look,
+    GrabCursor,
+    ReleaseCursor,
+    /// Held to orbit the camera without turning the player.
+    FreeLook,
+    /// Held to aim, which slows look speed.
+    Aim,
+    /// Held while aiming to still `BreathingSway` for a few seconds.
+    HoldBreath,
+    /// Held to drag the orbit focus across the screen plane.
+    PanPivot,
+    /// Moves the orbit focus back onto the pivot.
+    Recenter,
+    /// Swings the camera behind the last direction of travel, for rigs with `AutoRecenter`.
+    RecenterBehind,
+    /// Double-click a `Focusable` entity to orbit around it.
+    Focus,
+    /// Held with a bookmark key to store the current framing instead of recalling it.
+    BookmarkModifier,
+    Bookmark1,
+    Bookmark2,
+    Bookmark3,
+    Bookmark4,
+    Bookmark5,
+    Bookmark6,
+    Bookmark7,
+    Bookmark8,
+    Bookmark9,
+    /// Locks the view onto the nearest `LockOnTarget` in sight, or releases the lock.
+    LockOn,
+    /// Moves the lock to the next target to the right.
+    CycleTarget,
+    /// Flies the free-fly camera to the next or previous `Spectatable`.
+    SpectateNext,
+    SpectatePrevious,
+    /// Held to stop the top-down view scrolling at the window edges.
+    PauseEdgeScroll,
+    Frame,
+    ToggleFirstPerson,
+    SwapShoulder,
+    ToggleFreeFly,
+    ToggleTopDown,
+    ToggleIsometric,
+    ToggleFixed,
+    ToggleSideScroller,
+    ToggleTurntable,
+    ToggleChase,
+    ToggleFlight,
+    ToggleTwinStick,
+    ToggleRearView,
+    /// Mouse-look delta, read from leafwing in place of mouse motion.
+    #[cfg(feature = "leafwing")]
+    #[actionlike(DualAxis)]
+    LookAxis,
+    /// Zoom steps, read from leafwing in place of the mouse wheel.
+    #[cfg(feature = "leafwing")]
+    #[actionlike(Axis)]
+    ZoomAxis,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InputBinding {
+    Key(KeyCode),
+    Mouse(MouseButton),
+    #[cfg(feature = "gamepad")]
+    GamepadButton(GamepadButton),
+    /// Held while the axis is past the threshold; a negative threshold reads the negative side.
+    #[cfg(feature = "gamepad")]
+    GamepadAxis(GamepadAxis, f32),
+}
+
+impl From<KeyCode> for InputBinding {
+    fn from(key: KeyCode) -> Self {
+        InputBinding::Key(key)
+    }
+}
+
+impl From<MouseButton> for InputBinding {
+    fn from(button: MouseButton) -> Self {
+        InputBinding::Mouse(button)
+    }
+}
+
+#[cfg(feature = "gamepad")]
+impl From<GamepadButton> for InputBinding {
+    fn from(button: GamepadButton) -> Self {
+        InputBinding::GamepadButton(button)
+    }
+}
+
+/// Which keys, buttons and axes trigger each camera action; edit it at runtime to rebind.
+#[derive(Resource)]
+pub struct InputMap {
+    bindings: HashMap<CameraAction, Vec<InputBinding>>,
+}
+
+impl Default for InputMap {
+    fn default() -> Self {
+        let mut map = Self::empty();
+        map
+        .bind(CameraAction::MoveForward, KeyCode::KeyW)
+        .bind(CameraAction::MoveBack, KeyCode::KeyS)
+        .bind(CameraAction::MoveLeft, KeyCode::KeyA)
+        .bind(CameraAction::MoveRight, KeyCode::KeyD)
+        .bind(CameraAction::MoveUp, KeyCode::Space)
+        .bind(CameraAction::MoveDown, KeyCode::ControlLeft)
+        .bind(CameraAction::RotateLeft, KeyCode::KeyQ)
+        .bind(CameraAction::RotateRight, KeyCode::KeyE)
+        .bind(CameraAction::RollLeft, KeyCode::KeyQ)
+        .bind(CameraAction::RollRight, KeyCode::KeyE)
+        .bind(CameraAction::OrbitModifier, MouseButton::Left)
+        .bind(CameraAction::PanModifier, MouseButton::Right)
+        .bind(CameraAction::ZoomIn, KeyCode::Equal)
+        .bind(CameraAction::ZoomOut, KeyCode::Minus)
+        .bind(CameraAction::ScrollSpeedModifier, KeyCode::ShiftLeft)
+        .bind(CameraAction::ScrollFovModifier, KeyCode::ControlLeft)
+        .bind(CameraAction::Look, MouseButton::Right)
+        .bind(CameraAction::GrabCursor, MouseButton::Left)
+        .bind(CameraAction::ReleaseCursor, KeyCode::Escape)
+        .bind(CameraAction::FreeLook, KeyCode::AltLeft)
+        .bind(CameraAction::Aim, MouseButton::Right)
+        .bind(CameraAction::HoldBreath, KeyCode::ShiftLeft)
+        .bind(CameraAction::PanPivot, MouseButton::Middle)
+        .bind(CameraAction::Recenter, KeyCode::Home)
+        .bind(CameraAction::RecenterBehind, KeyCode::KeyT)
+        .bind(CameraAction::Focus, MouseButton::Left)
+        .bind(CameraAction::BookmarkModifier, KeyCode::ControlLeft)
+        .bind(CameraAction::Bookmark1, KeyCode::Digit1)
+        .bind(CameraAction::Bookmark2, KeyCode::Digit2)
+        .bind(CameraAction::Bookmark3, KeyCode::Digit3)
+        .bind(CameraAction::Bookmark4, KeyCode::Digit4)
+        .bind(CameraAction::Bookmark5, KeyCode::Digit5)
+        .bind(CameraAction::Bookmark6, KeyCode::Digit6)
+        .bind(CameraAction::Bookmark7, KeyCode::Digit7)
+        .bind(CameraAction::Bookmark8, KeyCode::Digit8)
+        .bind(CameraAction::Bookmark9, KeyCode::Digit9)
+        .bind(CameraAction::LockOn, KeyCode::KeyX)
+        .bind(CameraAction::CycleTarget, KeyCode::Tab)
+        .bind(CameraAction::SpectateNext, KeyCode::BracketRight)
+        .bind(CameraAction::SpectatePrevious, KeyCode::BracketLeft)
+        .bind(CameraAction::PauseEdgeScroll, KeyCode::ShiftLeft)
+        .bind(CameraAction::Frame, KeyCode::KeyF)
+        .bind(CameraAction::ToggleFirstPerson, KeyCode::KeyV)
+        .bind(CameraAction::SwapShoulder, KeyCode::KeyC)
+        .bind(CameraAction::ToggleFreeFly, KeyCode::F1)
+        .bind(CameraAction::ToggleTopDown, KeyCode::F2)
+        .bind(CameraAction::ToggleIsometric, KeyCode::F3)
+        .bind(CameraAction::ToggleFixed, KeyCode::F4)
+        .bind(CameraAction::ToggleSideScroller, KeyCode::F5)
+        .bind(CameraAction::ToggleTurntable, KeyCode::F6)
+        .bind(CameraAction::ToggleChase, KeyCode::F7)
+        .bind(CameraAction::ToggleFlight, KeyCode::F8)
+        .bind(CameraAction::ToggleTwinStick, KeyCode::F9)
+        .bind(CameraAction::ToggleRearView, KeyCode::KeyB);
+
+        #[cfg(feature = "gamepad")]
+        map
+        .bind(CameraAction::Aim, GamepadButton::LeftTrigger2)
+        .bind(CameraAction::HoldBreath, GamepadButton::LeftThumb)
+        .bind(CameraAction::LockOn, GamepadButton::RightThumb);
+        map
+    }
+}
+
+impl InputMap {
+    pub fn empty() -> Self {
+        Self {
+            bindings: HashMap::default(),
+        }
+    }
+
+    /// Adds a binding, keeping the action's existing ones.
+    pub fn bind(&mut self, action: CameraAction, binding: impl Into<InputBinding>) -> &mut Self {
+        self.bindings.entry(action).or_default().push(binding.into());
+        self
+    }
+
+    /// Replaces all of the action's bindings with `binding`.
+    pub fn rebind(&mut self, action: CameraAction, binding: impl Into<InputBinding>) -> &mut Self {
+        self.bindings.insert(action, vec![binding.into()]);
+        self
+    }
+
+    pub fn unbind(&mut self, action: CameraAction) -> &mut Self {
+        self.bindings.remove(&action);
+        self
+    }
+
+    pub fn bindings(&self, action: CameraAction) -> &[InputBinding] {
+        self.bindings.get(&action).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (CameraAction, &InputBinding)> {
+        self.bindings
+            .iter()
+            .flat_map(|(action, bindings)| bindings.iter().map(move |binding| (*action, binding)))
+    }
+}
+
+/// Reads camera actions through the `InputMap`.
+#[derive(SystemParam)]
+pub struct ActionInput<'w, 's> {
+    pub map: Res<'w, InputMap>,
+    keys: Res<'w, ButtonInput<KeyCode>>,
+    mouse: Res<'w, ButtonInput<MouseButton>>,
+    #[cfg(feature = "gamepad")]
+    gamepads: Query<'w, 's, (Entity, &'static Gamepad)>,
+    #[cfg(not(feature = "gamepad"))]
+    _state: std::marker::PhantomData<&'s ()>,
+    #[cfg(feature = "leafwing")]
+    leafwing: Option<Res<'w, leafwing_input_manager::prelude::ActionState<CameraAction>>>,
+}
+
+impl ActionInput<'_, '_> {
+    pub fn pressed(&self, action: CameraAction) -> bool {
+        self.pressed_by(action, None)
+    }
+
+    /// Axis bindings have no edge, so they never count as just pressed.
+    pub fn just_pressed(&self, action: CameraAction) -> bool {
+        self.just_pressed_by(action, None)
+    }
+
+    /// Like `pressed`, counting only the devices that drive a rig with `source`. Leafwing state
+    /// has no devices to tell apart, so it counts for every source.
+    pub fn pressed_by(&self, action: CameraAction, source: Option<&InputSource>) -> bool {
+        #[cfg(feature = "leafwing")]
+        if let Some(state) = &self.leafwing {
+            return state.pressed(&action);
+        }
+
+        self.map.bindings(action).iter().any(|binding| self.binding_pressed(binding, source))
+    }
+
+    pub fn just_pressed_by(&self, action: CameraAction, source: Option<&InputSource>) -> bool {
+        #[cfg(feature = "leafwing")]
+        if let Some(state) = &self.leafwing {
+            return state.just_pressed(&action);
+        }
+
+        self.map.bindings(action).iter().any(|binding| self.binding_just_pressed(binding, source))
+    }
+
+    /// Like `pressed_by`, ignoring any binding `excluded` also uses. Leafwing maps are taken as given.
+    pub fn pressed_without(&self, action: CameraAction, excluded: CameraAction, source: Option<&InputSource>) -> bool {
+        #[cfg(feature = "leafwing")]
+        if self.leafwing.is_some() {
+            return self.pressed_by(action, source);
+        }
+
+        self.bindings_without(action, excluded).any(|binding| self.binding_pressed(binding, source))
+    }
+
+    pub fn just_pressed_without(&self, action: CameraAction, excluded: CameraAction, source: Option<&InputSource>) -> bool {
+        #[cfg(feature = "leafwing")]
+        if self.leafwing.is_some() {
+            return self.just_pressed_by(action, source);
+        }
+
+        self.bindings_without(action, excluded).any(|binding| self.binding_just_pressed(binding, source))
+    }
+
+    fn bindings_without(&self, action: CameraAction, excluded: CameraAction) -> impl Iterator<Item = &InputBinding> {
+        let excluded = self.map.bindings(excluded);
+        self.map.bindings(action).iter().filter(move |binding| !excluded.contains(binding))
+    }
+
+    /// Gamepads that drive a rig with `source`.
+    #[cfg(feature = "gamepad")]
+    fn gamepads<'a>(&'a self, source: Option<&'a InputSource>) -> impl Iterator<Item = &'a Gamepad> {
+        self.gamepads
+            .iter()
+            .filter(move |(entity, _)| accepts_gamepad(source, *entity))
+            .map(|(_, gamepad)| gamepad)
+    }
+
+    fn binding_pressed(&self, binding: &InputBinding, source: Option<&InputSource>) -> bool {
+        match *binding {
+            InputBinding::Key(key) => accepts_keyboard_mouse(source) && self.keys.pressed(key),
+            InputBinding::Mouse(button) => accepts_keyboard_mouse(source) && self.mouse.pressed(button),
+            #[cfg(feature = "gamepad")]
+            InputBinding::GamepadButton(button) => self.gamepads(source).any(|gamepad| gamepad.pressed(button)),
+            #[cfg(feature = "gamepad")]
+            InputBinding::GamepadAxis(axis, threshold) => self.gamepads(source).any(|gamepad| {
+                let value = gamepad.get(axis).unwrap_or(0.);
+                if threshold < 0. { value <= threshold } else { value >= threshold }
+            }),
+        }
+    }
+
+    fn binding_just_pressed(&self, binding: &InputBinding, source: Option<&InputSource>) -> bool {
+        match *binding {
+            InputBinding::Key(key) => accepts_keyboard_mouse(source) && self.keys.just_pressed(key),
+            InputBinding::Mouse(button) => accepts_keyboard_mouse(source) && self.mouse.just_pressed(button),
+            #[cfg(feature = "gamepad")]
+            InputBinding::GamepadButton(button) => self.gamepads(source).any(|gamepad| gamepad.just_pressed(button)),
+            #[cfg(feature = "gamepad")]
+            InputBinding::GamepadAxis(..) => false,
+        }
+    }
+
+    /// Look delta from leafwing, when it drives the camera.
+    pub fn look_axis(&self) -> Option<Vec2> {
+        #[cfg(feature = "leafwing")]
+        if let Some(state) = &self.leafwing {
+            return Some(state.axis_pair(&CameraAction::LookAxis));
+        }
+        None
+    }
+
+    /// Zoom steps from leafwing, when it drives the camera.
+    pub fn zoom_axis(&self) -> Option<f32> {
+        #[cfg(feature = "leafwing")]
+        if let Some(state) = &self.leafwing {
+            return Some(state.value(&CameraAction::ZoomAxis));
+        }
+        None
+    }
+
+    /// Whether a held modifier redirects the scroll wheel away from zooming.
+    pub fn scroll_modified(&self) -> bool {
+        self.scroll_modified_by(None)
+    }
+
+    /// Like `scroll_modified`, counting only the devices that drive a rig with `source`.
+    pub fn scroll_modified_by(&self, source: Option<&InputSource>) -> bool {
+        self.pressed_by(CameraAction::ScrollSpeedModifier, source)
+            || self.pressed_by(CameraAction::ScrollFovModifier, source)
+    }
+
+    /// -1, 0 or 1 from a pair of opposing actions.
+    pub fn axis(&self, negative: CameraAction, positive: CameraAction) -> f32 {
+        let mut value = 0.;
+        if self.pressed(positive) { value += 1.; }
+        if self.pressed(negative) { value -= 1.; }
+        value
+    }
+}
+
+/// Whether each of `MODAL_ACTIONS` is on, after applying its hold or toggle activation. Each
+/// `InputSource` a rig listens to keeps its own state, and `None` stands for every device at once.
+#[derive(Resource, Default)]
+pub struct ModalActions {
+    active: HashSet<(CameraAction, Option<InputSource>)>,
+    previous: HashSet<(CameraAction, Option<InputSource>)>,
+}
+
+impl ModalActions {
+    pub fn active(&self, action: CameraAction) -> bool {
+        self.active_for(action, None)
+    }
+
+    pub fn just_activated(&self, action: CameraAction) -> bool {
+        self.just_activated_for(action, None)
+    }
+
+    pub fn just_deactivated(&self, action: CameraAction) -> bool {
+        self.just_deactivated_for(action, None)
+    }
+
+    /// Whether the action is on for a rig with `source`.
+    pub fn active_for(&self, action: CameraAction, source: Option<&InputSource>) -> bool {
+        self.active.contains(&(action, source.copied()))
+    }
+
+    pub fn just_activated_for(&self, action: CameraAction, source: Option<&InputSource>) -> bool {
+        let key = (action, source.copied());
+        self.active.contains(&key) && !self.previous.contains(&key)
+    }
+
+    pub fn just_deactivated_for(&self, action: CameraAction, source: Option<&InputSource>) -> bool {
+        let key = (action, source.copied());
+        !self.active.contains(&key) && self.previous.contains(&key)
+    }
+
+    /// Forces an action on or off, e.g. when the state it controls changed some other way, without
+    /// reporting an edge for it.
+    pub fn set(&mut self, action: CameraAction, active: bool) {
+        self.set_for(action, None, active);
+    }
+
+    pub fn set_for(&mut self, action: CameraAction, source: Option<&InputSource>, active: bool) {
+        let key = (action, source.copied());
+        if active {
+            self.active.insert(key);
+            self.previous.insert(key);
+        } else {
+            self.active.remove(&key);
+            self.previous.remove(&key);
+        }
+    }
+}
+
+pub fn update_modal_actions(
+    settings: Res<CameraSettings>,
+    actions: ActionInput,
+    rigs: Query<&InputSource>,
+    mut modal: ResMut<ModalActions>,
+) {
+    let mut sources = vec![None];
+    for &source in rigs.iter() {
+        if !sources.contains(&Some(source)) {
+            sources.push(Some(source));
+        }
+    }
+
+    modal.previous = modal.active.clone();
+    modal.active.retain(|(_, source)| sources.contains(source));
+
+    for source in &sources {
+        let source = source.as_ref();
+        for action in MODAL_ACTIONS {
+            // The look button is taken under `HoldToLook`, so aiming only listens to its other bindings.
+            let excluded = (action == CameraAction::Aim && settings.look_mode == LookMode::HoldToLook)
+                .then_some(CameraAction::Look);
+            let pressed = excluded.map_or_else(
+                || actions.pressed_by(action, source),
+                |excluded| actions.pressed_without(action, excluded, source),
+            );
+            let just_pressed = excluded.map_or_else(
+                || actions.just_pressed_by(action, source),
+                |excluded| actions.just_pressed_without(action, excluded, source),
+            );
+
+            let active = match settings.activation(action) {
+                Activation::Hold => pressed,
+                Activation::Toggle => modal.active_for(action, source) != just_pressed,
+            };
+            let key = (action, source.copied());
+            if active {
+                modal.active.insert(key);
+            } else {
+                modal.active.remove(&key);
+            }
+        }
+    }
+}
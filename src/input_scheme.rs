@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+
+use crate::input::MouseDeltas;
+#[cfg(feature = "gamepad")]
+use crate::settings::CameraSettings;
+
+/// The device family that produced the most recent meaningful input.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ActiveInputScheme {
+    #[default]
+    KeyboardMouse,
+    Gamepad,
+    Touch,
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct InputSchemeChanged {
+    pub from: ActiveInputScheme,
+    pub to: ActiveInputScheme,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn detect_input_scheme(
+    #[cfg(feature = "gamepad")]
+    settings: Res<CameraSettings>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    deltas: Res<MouseDeltas>,
+    touches: Res<Touches>,
+    #[cfg(feature = "gamepad")]
+    gamepads: Query<&Gamepad>,
+    mut scheme: ResMut<ActiveInputScheme>,
+    mut events: EventWriter<InputSchemeChanged>,
+) {
+    #[cfg(feature = "gamepad")]
+    let gamepad = gamepads.iter().any(|gamepad| {
+        gamepad.get_just_pressed().next().is_some()
+            || settings.left_stick.apply(gamepad.left_stick()) != Vec2::ZERO
+            || settings.right_stick.apply(gamepad.right_stick()) != Vec2::ZERO
+    });
+    #[cfg(not(feature = "gamepad"))]
+    let gamepad = false;
+    let touch = touches.any_just_pressed();
+    let keyboard_mouse = keys.get_just_pressed().next().is_some()
+        || mouse.get_just_pressed().next().is_some()
+        || deltas.scroll != 0.
+        // Touch screens also report mouse motion, so ignore it during a gesture.
+        || (deltas.motion != Vec2::ZERO && touches.iter().next().is_none());
+
+    let detected = if gamepad {
+        ActiveInputScheme::Gamepad
+    } else if touch {
+        ActiveInputScheme::Touch
+    } else if keyboard_mouse {
+        ActiveInputScheme::KeyboardMouse
+    } else {
+        return;
+    };
+
+    if *scheme != detected {
+        events.send(InputSchemeChanged {
+            from: *scheme,
+            to: detected,
+        });
+        *scheme = detected;
+    }
+}
@@ -0,0 +1,129 @@
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::{
+    prelude::*,
+    render::camera::ScalingMode,
+};
+
+use crate::{
+    angle::{
+        lerp_angle,
+        wrap_angle,
+    },
+    easing::smoothstep,
+    input_map::{
+        ActionInput,
+        CameraAction,
+    },
+    mode::CameraMode,
+    rig::CameraRig,
+    split_screen::InputSource,
+};
+
+#[derive(Component)]
+pub struct Isometric {
+    /// Elevation above the horizon, in radians.
+    pub pitch: f32,
+    pub snap_angle: f32,
+    pub rotate_duration: f32,
+
+    pub start_yaw: f32,
+    pub target_yaw: f32,
+    pub elapsed: f32,
+}
+
+impl Default for Isometric {
+    fn default() -> Self {
+        Self {
+            pitch: 35f32.to_radians(),
+            snap_angle: 45f32.to_radians(),
+            rotate_duration: 0.25,
+
+            start_yaw: 0.,
+            target_yaw: 0.,
+            elapsed: 0.,
+        }
+    }
+}
+
+impl Isometric {
+    /// Starts at `yaw` and eases to the nearest snap increment.
+    pub fn facing(yaw: f32) -> Self {
+        let mut isometric = Self::default();
+        isometric.start_yaw = yaw;
+        isometric.target_yaw = wrap_angle((yaw / isometric.snap_angle).round() * isometric.snap_angle);
+        isometric
+    }
+
+    pub fn rotate(&mut self, current_yaw: f32, steps: f32) {
+        self.start_yaw = current_yaw;
+        // Kept wrapped like the rig's yaw, so the turn never winds up past a full circle.
+        self.target_yaw = wrap_angle(self.target_yaw + steps * self.snap_angle);
+        self.elapsed = 0.;
+    }
+
+    pub fn yaw(&self) -> f32 {
+        let t = if self.rotate_duration > 0. { (self.elapsed / self.rotate_duration).min(1.) } else { 1. };
+        lerp_angle(self.start_yaw, self.target_yaw, smoothstep(t))
+    }
+}
+
+pub fn toggle_isometric(
+    mut commands: Commands,
+    actions: ActionInput,
+    mut query: Query<(Entity, &mut CameraRig, Option<&InputSource>)>,
+) {
+    for (pivot, mut rig, source) in query.iter_mut() {
+        if !actions.just_pressed_by(CameraAction::ToggleIsometric, source) {
+            continue;
+        }
+        if rig.mode == CameraMode::Isometric {
+            rig.mode = CameraMode::Orbit;
+        } else {
+            commands.entity(pivot).insert(Isometric::facing(rig.yaw));
+            rig.mode = CameraMode::Isometric;
+        }
+    }
+}
+
+pub fn update_isometric(
+    time: Res<Time>,
+    actions: ActionInput,
+    mut rigs: Query<(&mut CameraRig, &mut Isometric, Option<&InputSource>)>,
+) {
+    for (mut rig, mut isometric, source) in rigs.iter_mut() {
+        if rig.mode != CameraMode::Isometric {
+            continue;
+        }
+
+        let mut steps = 0.;
+        if actions.just_pressed_by(CameraAction::RotateLeft, source) { steps += 1.; }
+        if actions.just_pressed_by(CameraAction::RotateRight, source) { steps -= 1.; }
+
+        if steps != 0. {
+            let yaw = rig.yaw;
+            isometric.rotate(yaw, steps);
+        }
+        isometric.elapsed += time.delta_secs();
+
+        rig.yaw = isometric.yaw();
+        rig.camera_pitch = FRAC_PI_2 - isometric.pitch;
+    }
+}
+
+pub fn sync_orthographic_height(
+    rigs: Query<&CameraRig>,
+    mut projections: Query<&mut Projection>,
+) {
+    for rig in rigs.iter() {
+        if rig.mode != CameraMode::Isometric {
+            continue;
+        }
+        let Some(camera_entity) = rig.camera_entity else { continue };
+        let Ok(mut projection) = projections.get_mut(camera_entity) else { continue };
+
+        if let Projection::Orthographic(orthographic) = projection.as_mut() {
+            orthographic.scaling_mode = ScalingMode::FixedVertical { viewport_height: rig.camera_distance };
+        }
+    }
+}
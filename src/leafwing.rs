@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::{
+    ActionState,
+    InputManagerPlugin,
+    MouseMove,
+    MouseScrollAxis,
+};
+#[cfg(feature = "gamepad")]
+use leafwing_input_manager::{
+    axislike::AxisDirection,
+    prelude::GamepadControlDirection,
+};
+
+use crate::input_map::{
+    CameraAction,
+    InputBinding,
+    InputMap,
+};
+
+/// Has the camera read a leafwing `ActionState<CameraAction>` resource instead of raw devices.
+///
+/// Bindings come from a leafwing `InputMap<CameraAction>` resource, converted from the
+/// crate's default `InputMap` when none is inserted.
+pub struct LeafwingCameraPlugin;
+
+impl Plugin for LeafwingCameraPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<InputManagerPlugin<CameraAction>>() {
+            app.add_plugins(InputManagerPlugin::<CameraAction>::default());
+        }
+        if !app.world().contains_resource::<leafwing_input_manager::prelude::InputMap<CameraAction>>() {
+            app.insert_resource(leafwing_input_map(&InputMap::default()));
+        }
+        app.init_resource::<ActionState<CameraAction>>();
+    }
+}
+
+/// Leafwing bindings equivalent to `map`, plus mouse motion and wheel for the look and zoom axes.
+pub fn leafwing_input_map(map: &InputMap) -> leafwing_input_manager::prelude::InputMap<CameraAction> {
+    let mut leafwing = leafwing_input_manager::prelude::InputMap::default()
+        .with_dual_axis(CameraAction::LookAxis, MouseMove::default())
+        .with_axis(CameraAction::ZoomAxis, MouseScrollAxis::Y);
+
+    for (action, binding) in map.iter() {
+        match *binding {
+            InputBinding::Key(key) => {
+                leafwing.insert(action, key);
+            }
+            InputBinding::Mouse(button) => {
+                leafwing.insert(action, button);
+            }
+            #[cfg(feature = "gamepad")]
+            InputBinding::GamepadButton(button) => {
+                leafwing.insert(action, button);
+            }
+            #[cfg(feature = "gamepad")]
+            InputBinding::GamepadAxis(axis, threshold) => {
+                let direction = if threshold < 0. { AxisDirection::Negative } else { AxisDirection::Positive };
+                leafwing.insert(action, GamepadControlDirection {
+                    axis,
+                    direction,
+                    threshold: threshold.abs(),
+                });
+            }
+        }
+    }
+
+    leafwing
+}
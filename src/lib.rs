@@ -0,0 +1,557 @@
+use bevy::prelude::*;
+
+pub mod aim;
+pub mod angle;
+pub mod attention;
+pub mod bookmarks;
+pub mod builder;
+pub mod chase;
+#[cfg(feature = "collision")]
+pub mod collision;
+pub mod composition;
+pub mod confiner;
+pub mod cursor;
+#[cfg(feature = "debug-ui")]
+pub mod debug_ui;
+pub mod dolly;
+pub mod easing;
+pub mod fade;
+pub mod fixed;
+pub mod flight;
+pub mod fly;
+pub mod focus;
+pub mod follow;
+pub mod frame;
+#[cfg(feature = "gamepad")]
+pub mod gamepad;
+pub mod input;
+pub mod input_map;
+pub mod input_scheme;
+pub mod isometric;
+#[cfg(feature = "leafwing")]
+pub mod leafwing;
+pub mod lock_on;
+pub mod minimap;
+pub mod mode;
+pub mod player;
+pub mod rail;
+pub mod rear_view;
+pub mod rig;
+pub mod scene;
+pub mod settings;
+#[cfg(feature = "shake")]
+pub mod shake;
+pub mod side_scroller;
+pub mod spectator;
+pub mod split_screen;
+pub mod target;
+pub mod top_down;
+pub mod touch;
+pub mod turntable;
+pub mod twin_stick;
+pub mod virtual_camera;
+
+pub mod prelude {
+    pub use crate::{
+        CameraSet,
+        OrbitCameraPlugin,
+        aim::{
+            AimDownSights,
+            Crosshair,
+        },
+        attention::PointOfInterest,
+        bookmarks::{
+            BookmarkFlight,
+            CameraBookmark,
+            CameraBookmarks,
+        },
+        builder::{
+            OrbitCameraBuilder,
+            SpawnOrbitCameraExt,
+        },
+        chase::ChaseCamera,
+        composition::{
+            Composition,
+            CompositionPreset,
+        },
+        confiner::{
+            CameraConfiner,
+            ConfinerShape,
+            ConfinerVolume,
+        },
+        cursor::CursorLook,
+        dolly::{
+            DollyZoom,
+            DollyZoomEffect,
+        },
+        easing::Easing,
+        fixed::{
+            CameraVolume,
+            FixedCamera,
+        },
+        flight::FlightCamera,
+        focus::{
+            FocusTarget,
+            Focusable,
+        },
+        follow::{
+            AutoRecenter,
+            CameraFollow,
+            Grounded,
+            SpeedZoom,
+        },
+        frame::FrameEntity,
+        input::{
+            MouseDeltas,
+            OrbitInertia,
+        },
+        input_map::{
+            ActionInput,
+            CameraAction,
+            InputBinding,
+            InputMap,
+            ModalActions,
+        },
+        input_scheme::{
+            ActiveInputScheme,
+            InputSchemeChanged,
+        },
+        isometric::Isometric,
+        lock_on::{
+            LockOn,
+            LockOnTarget,
+            SoftLock,
+        },
+        minimap::{
+            Minimap,
+            MinimapRotation,
+        },
+        mode::{
+            CameraMode,
+            CameraModeChanged,
+            CameraModeState,
+            CameraSpring,
+        },
+        player::{
+            Player,
+            SpeedFov,
+        },
+        rail::RailCamera,
+        rear_view::RearView,
+        rig::{
+            CameraLens,
+            CameraRig,
+            FovZoom,
+            RollSource,
+            ZoomMode,
+        },
+        settings::{
+            Activation,
+            CameraSettings,
+            LookMode,
+            LookSmoothing,
+            ResponseCurve,
+            ZoomSmoothing,
+        },
+        side_scroller::SideScroller,
+        spectator::{
+            Spectatable,
+            Spectator,
+        },
+        split_screen::{
+            InputSource,
+            ScreenCorner,
+            ScreenSlot,
+        },
+        target::{
+            CameraTarget,
+            CameraTargetLost,
+            GroupFraming,
+            GroupMember,
+            LostTargetKind,
+            SetCameraTarget,
+            TargetFraming,
+            TargetGroup,
+            TargetLostBehavior,
+            TargetTransitionFinished,
+            TargetTransitionStarted,
+        },
+        top_down::TopDown,
+        turntable::Turntable,
+        twin_stick::TwinStick,
+        virtual_camera::{
+            CameraBrain,
+            VirtualCamera,
+            VirtualCameraChanged,
+        },
+    };
+
+    #[cfg(feature = "leafwing")]
+    pub use crate::leafwing::LeafwingCameraPlugin;
+
+    #[cfg(feature = "collision")]
+    pub use crate::collision::{
+        CameraCollider,
+        CameraCollision,
+        CollisionProbe,
+        OcclusionAssist,
+        OcclusionStrategy,
+        SurfacePlane,
+        Whiskers,
+    };
+    #[cfg(feature = "gamepad")]
+    pub use crate::settings::StickSettings;
+    #[cfg(feature = "shake")]
+    pub use crate::shake::{
+        BreathingSway,
+        CameraShake,
+        HandheldSway,
+        HeadBob,
+        HitReaction,
+        HitTilt,
+        LandingDip,
+        MotionTilt,
+        PlayingShake,
+        Recoil,
+        RecoilKick,
+        ShakeImpulse,
+        ShakeOffset,
+        ShakePreset,
+        ShakeProfile,
+    };
+}
+
+pub use aim::{
+    AimDownSights,
+    Crosshair,
+};
+pub use attention::PointOfInterest;
+pub use bookmarks::{
+    BookmarkFlight,
+    CameraBookmark,
+    CameraBookmarks,
+};
+pub use builder::{
+    OrbitCameraBuilder,
+    SpawnOrbitCameraExt,
+};
+pub use chase::ChaseCamera;
+#[cfg(feature = "collision")]
+pub use collision::{
+    CameraCollider,
+    CameraCollision,
+    CollisionProbe,
+    OcclusionAssist,
+    OcclusionStrategy,
+    SurfacePlane,
+    Whiskers,
+};
+pub use composition::{
+    Composition,
+    CompositionPreset,
+};
+pub use confiner::{
+    CameraConfiner,
+    ConfinerShape,
+    ConfinerVolume,
+};
+pub use cursor::CursorLook;
+pub use dolly::{
+    DollyZoom,
+    DollyZoomEffect,
+};
+pub use easing::Easing;
+pub use fixed::{
+    CameraVolume,
+    FixedCamera,
+};
+pub use flight::FlightCamera;
+pub use focus::{
+    FocusTarget,
+    Focusable,
+};
+pub use follow::{
+    AutoRecenter,
+    CameraFollow,
+    Grounded,
+    SpeedZoom,
+};
+pub use frame::FrameEntity;
+pub use input::{
+    MouseDeltas,
+    OrbitInertia,
+};
+pub use input_map::{
+    ActionInput,
+    CameraAction,
+    InputBinding,
+    InputMap,
+    ModalActions,
+};
+pub use input_scheme::{
+    ActiveInputScheme,
+    InputSchemeChanged,
+};
+pub use isometric::Isometric;
+#[cfg(feature = "leafwing")]
+pub use leafwing::LeafwingCameraPlugin;
+pub use lock_on::{
+    LockOn,
+    LockOnTarget,
+    SoftLock,
+};
+pub use minimap::{
+    Minimap,
+    MinimapRotation,
+};
+pub use mode::{
+    CameraMode,
+    CameraModeChanged,
+    CameraModeState,
+    CameraSpring,
+};
+pub use player::{
+    Player,
+    SpeedFov,
+};
+pub use rail::RailCamera;
+pub use rear_view::RearView;
+pub use rig::{
+    CameraLens,
+    CameraRig,
+    FovZoom,
+    RollSource,
+    ZoomMode,
+};
+pub use settings::{
+    Activation,
+    CameraSettings,
+    LookMode,
+    LookSmoothing,
+    ResponseCurve,
+    ZoomSmoothing,
+};
+#[cfg(feature = "gamepad")]
+pub use settings::StickSettings;
+#[cfg(feature = "shake")]
+pub use shake::{
+    BreathingSway,
+    CameraShake,
+    HandheldSway,
+    HeadBob,
+    HitReaction,
+    HitTilt,
+    LandingDip,
+    MotionTilt,
+    PlayingShake,
+    Recoil,
+    RecoilKick,
+    ShakeImpulse,
+    ShakeOffset,
+    ShakePreset,
+    ShakeProfile,
+};
+pub use side_scroller::SideScroller;
+pub use spectator::{
+    Spectatable,
+    Spectator,
+};
+pub use split_screen::{
+    InputSource,
+    ScreenCorner,
+    ScreenSlot,
+};
+pub use target::{
+    CameraTarget,
+    CameraTargetLost,
+    GroupFraming,
+    GroupMember,
+    LostTargetKind,
+    SetCameraTarget,
+    TargetFraming,
+    TargetGroup,
+    TargetLostBehavior,
+    TargetTransitionFinished,
+    TargetTransitionStarted,
+};
+pub use top_down::TopDown;
+pub use turntable::Turntable;
+pub use twin_stick::TwinStick;
+pub use virtual_camera::{
+    CameraBrain,
+    VirtualCamera,
+    VirtualCameraChanged,
+};
+
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CameraSet {
+    /// Reads devices and updates rig angles, distance and mode.
+    Input,
+    /// Moves pivots and computes each mode's desired camera pose.
+    Rig,
+    /// Blends mode transitions and writes the camera and player transforms.
+    Apply,
+}
+
+pub struct OrbitCameraPlugin;
+
+impl Plugin for OrbitCameraPlugin {
+    fn build(&self, app: &mut App) {
+        app
+        .init_resource::<CameraSettings>()
+        .init_resource::<InputMap>()
+        .init_resource::<CursorLook>()
+        .init_resource::<input::MouseDeltas>()
+        .init_resource::<ModalActions>()
+        .init_resource::<CameraBookmarks>()
+        .init_resource::<ActiveInputScheme>()
+        .init_resource::<Spectator>()
+        .add_event::<CameraModeChanged>()
+        .add_event::<VirtualCameraChanged>()
+        .add_event::<InputSchemeChanged>()
+        .add_event::<SetCameraTarget>()
+        .add_event::<CameraTargetLost>()
+        .add_event::<DollyZoom>()
+        .add_event::<TargetTransitionStarted>()
+        .add_event::<TargetTransitionFinished>()
+        .add_event::<FrameEntity>()
+        .configure_sets(Update, (
+            CameraSet::Input,
+            CameraSet::Rig,
+            CameraSet::Apply,
+        ).chain())
+        .add_systems(Update, (
+            (
+                input::accumulate_mouse_deltas,
+                input_scheme::detect_input_scheme,
+                input_map::update_modal_actions,
+                cursor::grab_cursor,
+                input::process_mouse_events,
+                touch::process_touch_events,
+                input::pan_pivot,
+                input::scroll_fov,
+                rig::sync_camera_lens,
+                rig::update_fov_zoom,
+                dolly::start_dolly_zoom,
+                dolly::update_dolly_zoom,
+                (
+                    frame::frame_hovered,
+                    frame::frame_entities,
+                    focus::pick_focus,
+                    focus::update_focus,
+                ).chain(),
+                bookmarks::use_bookmarks,
+                bookmarks::fly_to_bookmarks,
+                follow::auto_recenter,
+                follow::speed_zoom,
+                attention::attend_points_of_interest,
+                target::apply_set_camera_target,
+            ).chain(),
+            (
+                input::toggle_first_person,
+                input::update_aim,
+                input::swap_shoulder,
+                input::roll_camera,
+                fly::toggle_free_fly,
+                top_down::toggle_top_down,
+                isometric::toggle_isometric,
+                fixed::toggle_fixed,
+                side_scroller::toggle_side_scroller,
+                turntable::toggle_turntable,
+                chase::toggle_chase,
+                flight::toggle_flight,
+                twin_stick::toggle_twin_stick,
+                rear_view::toggle_rear_view,
+            ).chain(),
+        ).chain().in_set(CameraSet::Input))
+        .add_systems(Update, (
+            fly::update_free_fly,
+            spectator::update_spectator,
+            top_down::update_top_down,
+            isometric::update_isometric,
+            composition::update_composition,
+            target::follow_camera_target,
+            target::frame_target_group,
+            lock_on::update_lock_on,
+            rig::update_camera,
+            follow::update_follow,
+            lock_on::apply_soft_lock,
+            aim::aim_down_sights,
+            fixed::update_fixed_camera,
+            side_scroller::update_side_scroller,
+            turntable::update_turntable,
+            chase::update_chase,
+            flight::update_flight,
+            rail::update_rail,
+            twin_stick::update_twin_stick,
+            virtual_camera::update_virtual_cameras,
+        ).chain().in_set(CameraSet::Rig))
+        .add_systems(Update, (
+            target::check_camera_entities,
+            mode::apply_camera_pose,
+            virtual_camera::update_camera_brains,
+            confiner::confine_cameras,
+            rig::update_player_visibility,
+            spectator::update_spectator_label,
+            aim::update_crosshair,
+            isometric::sync_orthographic_height,
+            split_screen::layout_split_screen,
+            rear_view::update_rear_view,
+            player::update_play,
+            player::update_speed_fov,
+            minimap::update_minimap,
+        ).chain().in_set(CameraSet::Apply));
+
+        // Collision goes last among the systems that move the pivot-following pose, so nothing
+        // pushes the camera back into geometry after it.
+        #[cfg(feature = "collision")]
+        app.add_systems(Update, (
+            collision::update_occlusion_assist,
+            collision::update_whiskers,
+            collision::update_spring_arm,
+            collision::clamp_to_ground,
+            collision::avoid_surfaces,
+            collision::detect_occluders,
+            collision::fade_occluders,
+        ).chain().after(aim::aim_down_sights).in_set(CameraSet::Rig));
+
+        // Shake lands on the final pose but before the confiner, so no kick carries the camera out
+        // of its volume.
+        #[cfg(feature = "shake")]
+        app
+        .add_event::<shake::ShakeImpulse>()
+        .add_event::<shake::RecoilKick>()
+        .add_event::<shake::HitReaction>()
+        .add_systems(Update, (
+            shake::receive_shake_impulses,
+            shake::update_camera_shake,
+            shake::receive_recoil_kicks,
+            shake::update_recoil,
+            shake::receive_hit_reactions,
+            shake::update_hit_tilt,
+            shake::update_landing_dip,
+            shake::update_motion_tilt,
+            shake::update_head_bob,
+            shake::update_breathing_sway,
+            shake::update_handheld_sway,
+            shake::apply_shake_offset,
+        ).chain().after(virtual_camera::update_camera_brains).before(confiner::confine_cameras).in_set(CameraSet::Apply));
+
+        #[cfg(feature = "gamepad")]
+        app
+        .init_resource::<gamepad::GamepadAxes>()
+        // Sticks turn and zoom the rigs just ahead of the mouse, which then eases and clamps the zoom.
+        .add_systems(Update, (
+            gamepad::process_gamepad_events,
+            twin_stick::read_aim_stick,
+        ).chain().after(input_map::update_modal_actions).before(input::process_mouse_events).in_set(CameraSet::Input));
+
+        #[cfg(feature = "debug-ui")]
+        {
+            if !app.is_plugin_added::<bevy_egui::EguiPlugin>() {
+                app.add_plugins(bevy_egui::EguiPlugin);
+            }
+            app.add_systems(Update, debug_ui::rig_inspector);
+        }
+    }
+}
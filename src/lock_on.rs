@@ -0,0 +1,246 @@
+use bevy::prelude::*;
+#[cfg(feature = "collision")]
+use bevy::render::primitives::Aabb;
+
+use crate::{
+    angle::{
+        angle_delta,
+        lerp_angle,
+    },
+    input_map::{
+        ActionInput,
+        CameraAction,
+    },
+    mode::CameraModeState,
+    rig::CameraRig,
+    split_screen::InputSource,
+};
+#[cfg(feature = "collision")]
+use crate::collision::{
+    CameraCollider,
+    cast_ray,
+    collect_colliders,
+};
+#[cfg(feature = "gamepad")]
+use crate::gamepad::GamepadAxes;
+
+/// An entity the camera can lock onto.
+#[derive(Component, Default)]
+pub struct LockOnTarget;
+
+/// Turns the rig to keep a locked target in view beyond the player.
+#[derive(Component)]
+pub struct LockOn {
+    /// Farthest a target can be from the pivot to be picked.
+    pub max_distance: f32,
+    /// Distance at which an existing lock lets go.
+    pub break_distance: f32,
+    /// Half-angle of the cone in front of the camera that counts as in view, in radians.
+    pub view_angle: f32,
+    /// How quickly yaw turns towards the target, per second.
+    pub turn_rate: f32,
+    /// Seconds the target may stay hidden behind a collider before the lock breaks.
+    pub occlusion_grace: f32,
+    /// Right-stick deflection that counts as a flick to cycle targets.
+    pub flick_threshold: f32,
+
+    pub target: Option<Entity>,
+    pub occluded_for: f32,
+    pub flicked: bool,
+}
+
+impl Default for LockOn {
+    fn default() -> Self {
+        Self {
+            max_distance: 30.,
+            break_distance: 40.,
+            view_angle: 40f32.to_radians(),
+            turn_rate: 8.,
+            occlusion_grace: 0.5,
+            flick_threshold: 0.8,
+
+            target: None,
+            occluded_for: 0.,
+            flicked: false,
+        }
+    }
+}
+
+impl LockOn {
+    pub fn is_locked(&self) -> bool {
+        self.target.is_some()
+    }
+}
+
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn update_lock_on(
+    time: Res<Time>,
+    actions: ActionInput,
+    #[cfg(feature = "gamepad")]
+    gamepad: Res<GamepadAxes>,
+    #[cfg(feature = "collision")]
+    colliders: Query<(&Aabb, &GlobalTransform), With<CameraCollider>>,
+    targets: Query<(Entity, &GlobalTransform), With<LockOnTarget>>,
+    mut rigs: Query<(&mut CameraRig, &Transform, &mut LockOn, &CameraModeState, Option<&InputSource>)>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    #[cfg(feature = "collision")]
+    let colliders = collect_colliders(&colliders);
+
+    for (mut rig, transform, mut lock_on, state, source) in rigs.iter_mut() {
+        if !rig.mode.follows_pivot() {
+            lock_on.target = None;
+            continue;
+        }
+
+        let toggle = actions.just_pressed_by(CameraAction::LockOn, source);
+        let tab = if actions.just_pressed_by(CameraAction::CycleTarget, source) { 1. } else { 0. };
+
+        #[cfg(feature = "gamepad")]
+        let flick = {
+            let stick = gamepad.for_source(source).look.x;
+            let flicked = stick.abs() >= lock_on.flick_threshold;
+            let fresh = flicked && !lock_on.flicked && lock_on.is_locked();
+            lock_on.flicked = flicked;
+            if fresh { stick.signum() } else { 0. }
+        };
+        #[cfg(not(feature = "gamepad"))]
+        let flick = 0.;
+        let cycle = if flick != 0. { flick } else { tab };
+
+        let pivot = transform.translation;
+        let camera = state.current();
+        let view = camera.forward().with_y(0.).normalize_or_zero();
+        let view_yaw = CameraRig::yaw_towards(view);
+
+        // Candidates in reach and in front of the camera, with their bearing relative to the view.
+        let candidates: Vec<(Entity, f32, f32)> = targets
+            .iter()
+            .filter_map(|(entity, target)| {
+                let offset = target.translation() - pivot;
+                let distance = offset.length();
+                if distance > lock_on.max_distance {
+                    return None;
+                }
+                let bearing = angle_delta(view_yaw, CameraRig::yaw_towards(offset));
+                (bearing.abs() <= lock_on.view_angle).then_some((entity, distance, bearing))
+            })
+            .collect();
+
+        if toggle {
+            lock_on.target = if lock_on.is_locked() {
+                None
+            } else {
+                candidates
+                    .iter()
+                    .min_by(|a, b| a.1.total_cmp(&b.1))
+                    .map(|&(entity, ..)| entity)
+            };
+            lock_on.occluded_for = 0.;
+        } else if cycle != 0. {
+            if let Some(current) = lock_on.target.and_then(|target| targets.get(target).ok()) {
+                let current_bearing = angle_delta(view_yaw, CameraRig::yaw_towards(current.1.translation() - pivot));
+                // The next target over in the cycle direction, wrapping to the far side.
+                let ahead = |&(entity, _, bearing): &(Entity, f32, f32)| {
+                    (entity != current.0).then_some((entity, (bearing - current_bearing) * cycle))
+                };
+                let next = candidates
+                    .iter()
+                    .filter_map(ahead)
+                    .filter(|(_, gap)| *gap > 0.)
+                    .min_by(|a, b| a.1.total_cmp(&b.1))
+                    .or_else(|| candidates.iter().filter_map(ahead).min_by(|a, b| a.1.total_cmp(&b.1)));
+                if let Some((entity, _)) = next {
+                    lock_on.target = Some(entity);
+                    lock_on.occluded_for = 0.;
+                }
+            }
+        }
+
+        let Some((_, target)) = lock_on.target.and_then(|target| targets.get(target).ok()) else {
+            lock_on.target = None;
+            continue;
+        };
+        let target = target.translation();
+        let offset = target - pivot;
+        if offset.length() > lock_on.break_distance {
+            lock_on.target = None;
+            continue;
+        }
+
+        #[cfg(feature = "collision")]
+        {
+            let to_target = target - camera.translation;
+            let distance = to_target.length();
+            let blocked = Dir3::new(to_target)
+                .ok()
+                // Stop short so the target's own collider doesn't count.
+                .and_then(|direction| cast_ray(&colliders, camera.translation, direction, distance - 1.))
+                .is_some();
+            lock_on.occluded_for = if blocked { lock_on.occluded_for + delta_seconds } else { 0. };
+            if lock_on.occluded_for > lock_on.occlusion_grace {
+                lock_on.target = None;
+                continue;
+            }
+        }
+
+        if offset.with_y(0.).length_squared() > 1e-4 {
+            let blend = 1. - (-lock_on.turn_rate * delta_seconds).exp();
+            rig.yaw = lerp_angle(rig.yaw, CameraRig::yaw_towards(offset), blend);
+        }
+    }
+}
+
+/// Pulls the view part of the way from the pivot towards a secondary focus, leaving orbit input alone.
+#[derive(Component)]
+pub struct SoftLock {
+    pub focus: Entity,
+    /// Share of the aim given to `focus`, from 0 (the pivot) to 1 (the focus).
+    pub bias: f32,
+    /// How quickly the applied share follows `bias`, per second.
+    pub blend_rate: f32,
+
+    pub weight: f32,
+}
+
+impl SoftLock {
+    pub fn new(focus: Entity, bias: f32) -> Self {
+        Self {
+            focus,
+            bias,
+            blend_rate: 3.,
+
+            weight: 0.,
+        }
+    }
+}
+
+pub fn apply_soft_lock(
+    time: Res<Time>,
+    targets: Query<&GlobalTransform>,
+    mut rigs: Query<(&CameraRig, &mut SoftLock, &mut CameraModeState)>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for (rig, mut soft_lock, mut state) in rigs.iter_mut() {
+        let bias = soft_lock.bias.clamp(0., 1.);
+        let blend = 1. - (-soft_lock.blend_rate * delta_seconds).exp();
+        soft_lock.weight = soft_lock.weight.lerp(bias, blend);
+
+        if !rig.mode.follows_pivot() || soft_lock.weight <= 1e-4 {
+            continue;
+        }
+        let Ok(focus) = targets.get(soft_lock.focus) else {
+            continue;
+        };
+
+        let eye = state.desired.translation;
+        let to_focus = focus.translation() - eye;
+        if to_focus.length_squared() < 1e-6 {
+            continue;
+        }
+        let towards_focus = Transform::from_translation(eye).looking_to(to_focus, Vec3::Y).rotation;
+        state.desired.rotation = state.desired.rotation.slerp(towards_focus, soft_lock.weight);
+    }
+}
@@ -0,0 +1,177 @@
+use bevy::{
+    prelude::*,
+    render::{
+        camera::{
+            RenderTarget,
+            ScalingMode,
+        },
+        render_asset::RenderAssetUsages,
+        render_resource::{
+            Extent3d,
+            TextureDimension,
+            TextureFormat,
+            TextureUsages,
+        },
+    },
+};
+
+use crate::split_screen::ScreenCorner;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MinimapRotation {
+    /// World -Z stays at the top of the map.
+    #[default]
+    NorthUp,
+    /// The map turns so the followed entity's heading points up.
+    PlayerUp,
+}
+
+/// A top-down orthographic camera over the entity, usually the player, rendered into an image
+/// shown in a UI corner. The camera, image and UI node are created on the first frame.
+#[derive(Component, Clone, Debug)]
+pub struct Minimap {
+    /// World units covered by the map's height.
+    pub zoom: f32,
+    pub rotation: MinimapRotation,
+    /// Camera height above the entity.
+    pub height: f32,
+    /// Render target size, in pixels.
+    pub resolution: u32,
+    /// On-screen size, in logical pixels.
+    pub size: f32,
+    pub corner: ScreenCorner,
+    pub margin: f32,
+
+    pub camera: Option<Entity>,
+    pub node: Option<Entity>,
+    pub image: Option<Handle<Image>>,
+}
+
+impl Default for Minimap {
+    fn default() -> Self {
+        Self {
+            zoom: 40.,
+            rotation: MinimapRotation::NorthUp,
+            height: 50.,
+            resolution: 256,
+            size: 200.,
+            corner: ScreenCorner::BottomRight,
+            margin: 16.,
+
+            camera: None,
+            node: None,
+            image: None,
+        }
+    }
+}
+
+impl Minimap {
+    /// Camera pose over `transform`, looking straight down.
+    pub fn camera_transform(&self, transform: &Transform) -> Transform {
+        let up = match self.rotation {
+            MinimapRotation::NorthUp => Vec3::NEG_Z,
+            MinimapRotation::PlayerUp => {
+                let forward = transform.forward().with_y(0.);
+                forward.try_normalize().unwrap_or(Vec3::NEG_Z)
+            }
+        };
+        Transform::from_translation(transform.translation + Vec3::Y * self.height).looking_to(Vec3::NEG_Y, up)
+    }
+
+    fn node(&self) -> Node {
+        let margin = Val::Px(self.margin);
+        let mut node = Node {
+            position_type: PositionType::Absolute,
+            width: Val::Px(self.size),
+            height: Val::Px(self.size),
+            ..default()
+        };
+        match self.corner {
+            ScreenCorner::TopLeft => (node.top, node.left) = (margin, margin),
+            ScreenCorner::TopRight => (node.top, node.right) = (margin, margin),
+            ScreenCorner::BottomLeft => (node.bottom, node.left) = (margin, margin),
+            ScreenCorner::BottomRight => (node.bottom, node.right) = (margin, margin),
+        }
+        node
+    }
+
+    fn projection(&self) -> OrthographicProjection {
+        OrthographicProjection {
+            scaling_mode: ScalingMode::FixedVertical { viewport_height: self.zoom },
+            far: self.height * 2.,
+            ..OrthographicProjection::default_3d()
+        }
+    }
+}
+
+fn render_target(resolution: u32) -> Image {
+    let size = Extent3d {
+        width: resolution.max(1),
+        height: resolution.max(1),
+        ..default()
+    };
+    let mut image = Image::new_fill(
+        size,
+        TextureDimension::D2,
+        &[0, 0, 0, 255],
+        TextureFormat::Bgra8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.texture_descriptor.usage = TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST | TextureUsages::RENDER_ATTACHMENT;
+    image
+}
+
+pub fn update_minimap(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    mut minimaps: Query<(&Transform, &mut Minimap)>,
+    mut cameras: Query<(&mut Transform, &mut Projection), Without<Minimap>>,
+    mut nodes: Query<&mut Node>,
+) {
+    for (transform, mut minimap) in minimaps.iter_mut() {
+        let camera_transform = minimap.camera_transform(transform);
+
+        let Some((mut current, mut projection)) = minimap.camera.and_then(|camera| cameras.get_mut(camera).ok()) else {
+            let image = images.add(render_target(minimap.resolution));
+            let camera = commands
+                .spawn((
+                    Camera3d::default(),
+                    Camera {
+                        order: -1,
+                        target: RenderTarget::Image(image.clone()),
+                        ..default()
+                    },
+                    Projection::Orthographic(minimap.projection()),
+                    camera_transform,
+                ))
+                .id();
+            let node = commands
+                .spawn((minimap.node(), ImageNode::new(image.clone())))
+                .id();
+            minimap.camera = Some(camera);
+            minimap.node = Some(node);
+            minimap.image = Some(image);
+            continue;
+        };
+
+        *current = camera_transform;
+        // Only the fields the map owns are written, so other changes to the projection survive.
+        if let Projection::Orthographic(ortho) = projection.as_mut() {
+            let stale = !matches!(ortho.scaling_mode, ScalingMode::FixedVertical { viewport_height } if viewport_height == minimap.zoom);
+            if stale || ortho.far != minimap.height * 2. {
+                let target = minimap.projection();
+                ortho.scaling_mode = target.scaling_mode;
+                ortho.far = target.far;
+            }
+        }
+
+        if let Some(mut node) = minimap.node.and_then(|node| nodes.get_mut(node).ok()) {
+            let target = minimap.node();
+            if (node.width, node.height, node.top, node.right, node.bottom, node.left)
+                != (target.width, target.height, target.top, target.right, target.bottom, target.left)
+            {
+                *node = target;
+            }
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use bevy::prelude::*;
+
+use crate::{
+    easing::Easing,
+    rig::CameraRig,
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CameraMode {
+    #[default]
+    Orbit,
+    FirstPerson,
+    FreeFly,
+    TopDown,
+    Isometric,
+    Fixed,
+    SideScroller,
+    Turntable,
+    Chase,
+    Flight,
+    Rail,
+    TwinStick,
+}
+
+impl CameraMode {
+    /// Whether the pose is derived from the rig's yaw, pitch and distance around the pivot.
+    pub fn follows_pivot(self) -> bool {
+        matches!(self, CameraMode::Orbit | CameraMode::FirstPerson | CameraMode::Isometric)
+    }
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct CameraModeChanged {
+    pub rig: Entity,
+    pub from: CameraMode,
+    pub to: CameraMode,
+}
+
+/// Mode systems write the pose they want into `desired`; `apply_camera_pose`
+/// owns the camera transform and eases from the previous pose whenever the
+/// rig's mode changes, so switching never teleports the camera.
+#[derive(Component)]
+pub struct CameraModeState {
+    pub blend_duration: f32,
+    pub easing: Easing,
+    pub desired: Transform,
+
+    current: Transform,
+    mode: CameraMode,
+    from_mode: CameraMode,
+    from: Option<Transform>,
+    elapsed: f32,
+}
+
+impl Default for CameraModeState {
+    fn default() -> Self {
+        Self::new(CameraMode::Orbit, Transform::default())
+    }
+}
+
+impl CameraModeState {
+    pub fn new(mode: CameraMode, pose: Transform) -> Self {
+        Self {
+            blend_duration: 0.5,
+            easing: Easing::SmoothStep,
+            desired: pose,
+
+            current: pose,
+            mode,
+            from_mode: mode,
+            from: None,
+            elapsed: 0.,
+        }
+    }
+
+    /// The mode the applied pose is blending towards.
+    pub fn mode(&self) -> CameraMode {
+        self.mode
+    }
+
+    /// The pose written to the camera last frame.
+    pub fn current(&self) -> Transform {
+        self.current
+    }
+
+    pub fn is_blending(&self) -> bool {
+        self.from.is_some()
+    }
+
+    /// Eased blend progress towards the current mode, 1 when settled.
+    pub fn progress(&self) -> f32 {
+        if self.from.is_none() || self.blend_duration <= 0. {
+            return 1.;
+        }
+        self.easing.apply(self.elapsed / self.blend_duration)
+    }
+
+    /// How much `mode` contributes to the applied pose.
+    pub fn weight_of(&self, mode: CameraMode) -> f32 {
+        let progress = self.progress();
+        if self.mode == mode {
+            progress
+        } else if self.from.is_some() && self.from_mode == mode {
+            1. - progress
+        } else {
+            0.
+        }
+    }
+
+    fn begin_transition(&mut self, mode: CameraMode) {
+        self.from = Some(self.current);
+        self.from_mode = self.mode;
+        self.mode = mode;
+        self.elapsed = 0.;
+    }
+
+    fn advance(&mut self, delta_seconds: f32) -> Transform {
+        self.elapsed += delta_seconds;
+
+        let pose = match self.from {
+            Some(from) => {
+                let t = self.progress();
+                Transform {
+                    translation: from.translation.lerp(self.desired.translation, t),
+                    rotation: from.rotation.slerp(self.desired.rotation, t),
+                    scale: from.scale.lerp(self.desired.scale, t),
+                }
+            }
+            None => self.desired,
+        };
+        if self.elapsed >= self.blend_duration {
+            self.from = None;
+        }
+
+        self.current = pose;
+        pose
+    }
+}
+
+/// Runs the applied pose through a damped spring, which carries momentum through direction
+/// changes instead of decaying straight at the goal.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct CameraSpring {
+    /// Spring constant; the natural frequency is its square root, in radians per second.
+    pub stiffness: f32,
+    /// 1 is critically damped, lower values overshoot and higher values lag.
+    pub damping_ratio: f32,
+    pub rotation_stiffness: f32,
+    pub rotation_damping_ratio: f32,
+
+    pub pose: Option<Transform>,
+    pub velocity: Vec3,
+    pub angular_velocity: Vec3,
+}
+
+impl Default for CameraSpring {
+    fn default() -> Self {
+        Self::critically_damped(100.)
+    }
+}
+
+impl CameraSpring {
+    pub fn new(stiffness: f32, damping_ratio: f32) -> Self {
+        Self {
+            stiffness,
+            damping_ratio,
+            rotation_stiffness: stiffness,
+            rotation_damping_ratio: damping_ratio,
+
+            pose: None,
+            velocity: Vec3::ZERO,
+            angular_velocity: Vec3::ZERO,
+        }
+    }
+
+    pub fn critically_damped(stiffness: f32) -> Self {
+        Self::new(stiffness, 1.)
+    }
+
+    /// Advances the spring towards `goal`, in fixed substeps so stiff springs stay stable.
+    pub fn step(&mut self, goal: Transform, delta_seconds: f32) -> Transform {
+        let Some(mut pose) = self.pose else {
+            self.pose = Some(goal);
+            return goal;
+        };
+
+        let steps = (delta_seconds * 120.).ceil().max(1.);
+        let dt = delta_seconds / steps;
+        let damping = 2. * self.damping_ratio * self.stiffness.sqrt();
+        let rotation_damping = 2. * self.rotation_damping_ratio * self.rotation_stiffness.sqrt();
+
+        for _ in 0..steps as u32 {
+            let acceleration = (goal.translation - pose.translation) * self.stiffness - self.velocity * damping;
+            self.velocity += acceleration * dt;
+            pose.translation += self.velocity * dt;
+
+            let mut delta = goal.rotation * pose.rotation.inverse();
+            if delta.w < 0. {
+                // Turn the short way round.
+                delta = -delta;
+            }
+            let error = delta.to_scaled_axis();
+            let angular_acceleration = error * self.rotation_stiffness - self.angular_velocity * rotation_damping;
+            self.angular_velocity += angular_acceleration * dt;
+            pose.rotation = (Quat::from_scaled_axis(self.angular_velocity * dt) * pose.rotation).normalize();
+        }
+        pose.scale = goal.scale;
+
+        self.pose = Some(pose);
+        pose
+    }
+}
+
+pub fn apply_camera_pose(
+    time: Res<Time>,
+    mut events: EventWriter<CameraModeChanged>,
+    mut rigs: Query<(Entity, &CameraRig, &mut CameraModeState, Option<&mut CameraSpring>)>,
+    mut cameras: Query<&mut Transform, Without<CameraRig>>,
+) {
+    for (entity, rig, mut state, spring) in rigs.iter_mut() {
+        if state.mode != rig.mode {
+            events.send(CameraModeChanged {
+                rig: entity,
+                from: state.mode,
+                to: rig.mode,
+            });
+            state.begin_transition(rig.mode);
+        }
+
+        let mut pose = state.advance(time.delta_secs());
+        if let Some(mut spring) = spring {
+            pose = spring.step(pose, time.delta_secs());
+        }
+
+        let Some(camera_entity) = rig.camera_entity else { continue };
+        if let Ok(mut transform) = cameras.get_mut(camera_entity) {
+            *transform = pose;
+        }
+    }
+}
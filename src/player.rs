@@ -0,0 +1,123 @@
+use bevy::prelude::*;
+
+use crate::{
+    aim::AimDownSights,
+    dolly::DollyZoomEffect,
+    easing::{
+        decay_blend,
+        smoothstep,
+    },
+    rig::{
+        CameraRig,
+        ZoomMode,
+    },
+    settings::CameraSettings,
+};
+
+#[derive(Component)]
+pub struct Player {
+    pub pos_translation: Vec3,
+    pub pos_rotation: Quat,
+    /// World velocity of the last movement step.
+    pub velocity: Vec3,
+    /// Rig whose pivot this player follows; `None` follows whichever rig moved last.
+    pub rig: Option<Entity>,
+}
+
+impl Default for Player {
+    fn default() -> Self {
+        Self {
+            pos_translation: Vec3::ZERO,
+            pos_rotation: Quat::IDENTITY,
+            velocity: Vec3::ZERO,
+            rig: None,
+        }
+    }
+}
+
+impl Player {
+    /// Whether this player follows `rig`'s pivot, either by name or as the unassigned player.
+    pub fn follows(&self, rig: Entity) -> bool {
+        self.rig.is_none_or(|own| own == rig)
+    }
+}
+
+/// The item paired with the player following `rig`, preferring one assigned to it by name over an
+/// unassigned one.
+pub fn rig_player<'a, T>(players: impl IntoIterator<Item = (&'a Player, T)>, rig: Entity) -> Option<T> {
+    let mut unassigned = None;
+    for (player, item) in players {
+        match player.rig {
+            Some(own) if own == rig => return Some(item),
+            None if unassigned.is_none() => unassigned = Some(item),
+            _ => {}
+        }
+    }
+    unassigned
+}
+
+/// Velocity of the player following `rig`; zero when none follows it.
+pub fn rig_velocity(players: &Query<&Player>, rig: Entity) -> Vec3 {
+    rig_player(players.iter().map(|player| (player, player.velocity)), rig).unwrap_or(Vec3::ZERO)
+}
+
+pub fn update_play (
+    time: Res<Time>,
+    settings: Res<CameraSettings>,
+    mut query: Query<(&Player, &mut Transform)>
+) {
+    let blend = decay_blend(settings.player_turn_half_life, time.delta_secs());
+
+    for (player, mut transform) in query.iter_mut() {
+        transform.translation = player.pos_translation;
+        transform.rotation = transform.rotation.slerp(player.pos_rotation, blend);
+    }
+}
+
+/// Widens the rig's field of view past its lens as the player speeds up, easing back at rest.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct SpeedFov {
+    /// Field of view added to the lens at or above `max_speed`, in radians.
+    pub extra_fov: f32,
+    pub min_speed: f32,
+    pub max_speed: f32,
+    /// Seconds for the field of view to close half the gap to its goal.
+    pub half_life: f32,
+}
+
+impl Default for SpeedFov {
+    fn default() -> Self {
+        Self {
+            extra_fov: 20f32.to_radians(),
+            min_speed: 4.,
+            max_speed: 25.,
+            half_life: 0.2,
+        }
+    }
+}
+
+pub fn update_speed_fov(
+    time: Res<Time>,
+    players: Query<&Player>,
+    rigs: Query<(Entity, &CameraRig, &SpeedFov, Option<&AimDownSights>), Without<DollyZoomEffect>>,
+    mut projections: Query<&mut Projection>,
+) {
+    for (entity, rig, kick, ads) in rigs.iter() {
+        // Lens zoom and the sights own the field of view while they are in use.
+        if matches!(rig.zoom_mode, ZoomMode::FieldOfView(_)) || ads.is_some_and(|ads| ads.weight > 0.) {
+            continue;
+        }
+        let Some(mut projection) = rig.camera_entity.and_then(|camera| projections.get_mut(camera).ok()) else {
+            continue;
+        };
+        let Projection::Perspective(perspective) = projection.as_mut() else {
+            continue;
+        };
+
+        let speed = rig_velocity(&players, entity).length();
+        let range = (kick.max_speed - kick.min_speed).max(f32::EPSILON);
+        let t = smoothstep(((speed - kick.min_speed) / range).clamp(0., 1.));
+        let fov = rig.lens.fov + kick.extra_fov * t;
+        perspective.fov = perspective.fov.lerp(fov, decay_blend(kick.half_life, time.delta_secs()));
+    }
+}
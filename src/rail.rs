@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+
+use crate::{
+    mode::{
+        CameraMode,
+        CameraModeState,
+    },
+    player::{
+        Player,
+        rig_player,
+    },
+    rig::CameraRig,
+};
+
+/// Keeps the camera on an authored track, sliding to the point nearest the player.
+#[derive(Component)]
+pub struct RailCamera {
+    curve: CubicCurve<Vec3>,
+    pub samples_per_segment: usize,
+    /// How quickly the camera slides toward the nearest point on the rail, per second.
+    pub stiffness: f32,
+    /// Position on the rail, from 0 to the number of segments.
+    pub t: f32,
+}
+
+impl RailCamera {
+    /// Builds a Catmull-Rom rail; the first and last points only shape the ends.
+    pub fn through(points: impl Into<Vec<Vec3>>) -> Option<Self> {
+        let curve = CubicCardinalSpline::new_catmull_rom(points).to_curve().ok()?;
+        Some(Self {
+            curve,
+            samples_per_segment: 32,
+            stiffness: 4.,
+            t: 0.,
+        })
+    }
+
+    pub fn curve(&self) -> &CubicCurve<Vec3> {
+        &self.curve
+    }
+
+    pub fn length(&self) -> f32 {
+        self.curve.segments().len() as f32
+    }
+
+    pub fn closest_t(&self, point: Vec3) -> f32 {
+        let samples = self.curve.segments().len() * self.samples_per_segment.max(1);
+        (0..=samples)
+            .map(|i| i as f32 / samples as f32 * self.length())
+            .min_by(|a, b| {
+                let a = self.curve.position(*a).distance_squared(point);
+                let b = self.curve.position(*b).distance_squared(point);
+                a.total_cmp(&b)
+            })
+            .unwrap_or(0.)
+    }
+
+    pub fn advance(&mut self, target: Vec3, delta_seconds: f32) -> Transform {
+        let closest = self.closest_t(target);
+        self.t += (closest - self.t) * (1. - (-self.stiffness * delta_seconds).exp());
+
+        Transform::from_translation(self.curve.position(self.t)).looking_at(target, Vec3::Y)
+    }
+}
+
+pub fn update_rail(
+    time: Res<Time>,
+    players: Query<(&Player, &Transform), Without<CameraRig>>,
+    mut rigs: Query<(Entity, &CameraRig, &mut RailCamera, &mut CameraModeState, &Transform)>,
+) {
+    for (entity, rig, mut rail, mut state, pivot) in rigs.iter_mut() {
+        if rig.mode != CameraMode::Rail {
+            continue;
+        }
+        let player = rig_player(players.iter(), entity).map(|transform| transform.translation);
+        let target = player.unwrap_or(pivot.translation);
+        if state.mode() != CameraMode::Rail {
+            rail.t = rail.closest_t(target);
+        }
+        state.desired = rail.advance(target, time.delta_secs());
+    }
+}
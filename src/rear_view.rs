@@ -0,0 +1,125 @@
+use bevy::{
+    prelude::*,
+    render::camera::Viewport,
+    window::PrimaryWindow,
+};
+
+use crate::{
+    input_map::{
+        ActionInput,
+        CameraAction,
+    },
+    rig::CameraRig,
+    split_screen::{
+        InputSource,
+        ScreenCorner,
+        inset_rect,
+    },
+};
+
+/// A second camera on the pivot looking back the way the view came, drawn as an inset over the
+/// rig's own view and toggled with `CameraAction::ToggleRearView`. The rig's camera becomes the
+/// default UI camera so menus stay out of the inset.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct RearView {
+    pub enabled: bool,
+    /// Inset size as a fraction of the rig's view.
+    pub size: Vec2,
+    pub corner: ScreenCorner,
+    /// Gap to the edges of the view, in physical pixels.
+    pub margin: u32,
+    /// Camera position relative to the pivot.
+    pub offset: Vec3,
+    pub fov: f32,
+
+    pub camera: Option<Entity>,
+}
+
+impl Default for RearView {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            size: Vec2::new(0.3, 0.2),
+            corner: ScreenCorner::TopRight,
+            margin: 16,
+            offset: Vec3::new(0., 1., 0.),
+            fov: 50f32.to_radians(),
+
+            camera: None,
+        }
+    }
+}
+
+pub fn toggle_rear_view(
+    actions: ActionInput,
+    mut rigs: Query<(&mut RearView, Option<&InputSource>)>,
+) {
+    for (mut rear_view, source) in rigs.iter_mut() {
+        if actions.just_pressed_by(CameraAction::ToggleRearView, source) {
+            rear_view.enabled = !rear_view.enabled;
+        }
+    }
+}
+
+pub fn update_rear_view(
+    mut commands: Commands,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut rigs: Query<(Entity, &CameraRig, &mut RearView)>,
+    mut cameras: Query<(&mut Camera, &mut Transform)>,
+) {
+    let Ok(window) = windows.get_single() else { return };
+
+    for (pivot, rig, mut rear_view) in rigs.iter_mut() {
+        let Some(main) = rig.camera_entity else { continue };
+        let Ok((main_camera, _)) = cameras.get(main) else { continue };
+        let order = main_camera.order + 1;
+        let (origin, area) = main_camera.viewport
+            .as_ref()
+            .map_or((UVec2::ZERO, window.physical_size()), |viewport| (viewport.physical_position, viewport.physical_size));
+
+        let size = (area.as_vec2() * rear_view.size).as_uvec2();
+        let (physical_position, physical_size) = inset_rect(rear_view.corner, size, rear_view.margin, origin, area);
+        let viewport = Viewport {
+            physical_position,
+            physical_size,
+            ..default()
+        };
+
+        // The pivot's forward points back at the camera, so its own facing is the rear view.
+        let transform = Transform::from_translation(rear_view.offset);
+
+        let Some((mut camera, mut camera_transform)) = rear_view.camera.and_then(|camera| cameras.get_mut(camera).ok()) else {
+            let camera = commands
+                .spawn((
+                    Camera3d::default(),
+                    Camera {
+                        order,
+                        viewport: Some(viewport),
+                        is_active: rear_view.enabled,
+                        ..default()
+                    },
+                    Projection::Perspective(PerspectiveProjection {
+                        fov: rear_view.fov,
+                        ..default()
+                    }),
+                    transform,
+                ))
+                .set_parent(pivot)
+                .id();
+            commands.entity(main).insert(IsDefaultUiCamera);
+            rear_view.camera = Some(camera);
+            continue;
+        };
+
+        camera.is_active = rear_view.enabled;
+        camera.order = order;
+        if camera.viewport.as_ref().is_none_or(|current| {
+            current.physical_position != physical_position || current.physical_size != physical_size
+        }) {
+            camera.viewport = Some(viewport);
+        }
+        if *camera_transform != transform {
+            *camera_transform = transform;
+        }
+    }
+}
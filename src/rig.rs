@@ -0,0 +1,666 @@
+use bevy::prelude::*;
+
+use crate::{
+    angle::wrap_angle,
+    easing::{
+        smooth_damp,
+        smoothstep,
+    },
+    fade::MaterialFade,
+    fixed::{
+        ActiveFixedCamera,
+        FixedCamera,
+    },
+    input_map::{
+        ActionInput,
+        CameraAction,
+        ModalActions,
+    },
+    mode::{
+        CameraMode,
+        CameraModeState,
+    },
+    player::Player,
+    settings::{
+        CameraSettings,
+        ZoomSmoothing,
+    },
+    side_scroller::SideScroller,
+    split_screen::{
+        InputSource,
+        accepts_keyboard_mouse,
+    },
+    target::{
+        CameraTarget,
+        TargetGroup,
+    },
+};
+#[cfg(feature = "gamepad")]
+use crate::gamepad::GamepadAxes;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RollSource {
+    /// Roll only changes when set from code.
+    #[default]
+    Off,
+    /// `Q`/`E` roll the camera.
+    Keys,
+    /// The camera copies the followed target's bank angle.
+    Target,
+}
+
+/// Limits and feel for zooming by narrowing the field of view.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FovZoom {
+    pub min_fov: f32,
+    pub max_fov: f32,
+    /// Field of view change per wheel notch, in radians.
+    pub step: f32,
+    /// Change while a zoom key is held, in radians per second.
+    pub key_speed: f32,
+    pub smooth_time: f32,
+}
+
+impl Default for FovZoom {
+    fn default() -> Self {
+        Self {
+            min_fov: 10f32.to_radians(),
+            max_fov: 60f32.to_radians(),
+            step: 5f32.to_radians(),
+            key_speed: 40f32.to_radians(),
+            smooth_time: 0.1,
+        }
+    }
+}
+
+/// What zoom input changes.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ZoomMode {
+    /// Moves the camera along the orbit arm.
+    #[default]
+    Distance,
+    /// Keeps the camera still and narrows the lens, for scopes and binoculars.
+    FieldOfView(FovZoom),
+}
+
+/// Base field of view and clip planes of the rig's camera.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CameraLens {
+    /// Vertical field of view, in radians; ignored by orthographic cameras.
+    pub fov: f32,
+    pub near: f32,
+    pub far: f32,
+}
+
+impl Default for CameraLens {
+    fn default() -> Self {
+        let perspective = PerspectiveProjection::default();
+        Self {
+            fov: perspective.fov,
+            near: perspective.near,
+            far: perspective.far,
+        }
+    }
+}
+
+impl CameraLens {
+    /// Lens matching an existing projection, falling back to the defaults for what it lacks.
+    pub fn from_projection(projection: &Projection) -> Self {
+        match projection {
+            Projection::Perspective(perspective) => Self {
+                fov: perspective.fov,
+                near: perspective.near,
+                far: perspective.far,
+            },
+            Projection::Orthographic(orthographic) => Self {
+                near: orthographic.near,
+                far: orthographic.far,
+                ..default()
+            },
+        }
+    }
+
+    pub fn projection(&self) -> Projection {
+        Projection::Perspective(PerspectiveProjection {
+            fov: self.fov,
+            near: self.near,
+            far: self.far,
+            ..default()
+        })
+    }
+
+    /// Writes whichever of the lens values differ from `applied` into `projection`, leaving the
+    /// rest to the systems that animate them.
+    pub fn apply(&self, applied: Option<CameraLens>, projection: &mut Projection) {
+        let changed = |value: fn(&CameraLens) -> f32| applied.is_none_or(|applied| value(&applied) != value(self));
+        match projection {
+            Projection::Perspective(perspective) => {
+                if changed(|lens| lens.fov) { perspective.fov = self.fov; }
+                if changed(|lens| lens.near) { perspective.near = self.near; }
+                if changed(|lens| lens.far) { perspective.far = self.far; }
+            }
+            Projection::Orthographic(orthographic) => {
+                if changed(|lens| lens.near) { orthographic.near = self.near; }
+                if changed(|lens| lens.far) { orthographic.far = self.far; }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ZoomTween {
+    pub from: f32,
+    pub to: f32,
+    pub elapsed: f32,
+}
+
+#[derive(Component)]
+#[require(Transform, Visibility, CameraModeState)]
+pub struct CameraRig {
+    pub yaw: f32,
+    /// Camera yaw away from the player's heading while free-looking.
+    pub free_look_yaw: f32,
+    /// How quickly the camera swings back behind the player once free-look is released.
+    pub free_look_return_rate: f32,
+
+    /// Distance rendered this frame; it follows `target_distance`, which is what input changes.
+    pub camera_distance: f32,
+    pub target_distance: f32,
+    /// Rate of change of `camera_distance` under critically damped zoom.
+    pub zoom_velocity: f32,
+    /// Furthest the spring arm lets the camera sit this frame, while something blocks it.
+    pub collision_distance: Option<f32>,
+    pub camera_pitch: f32,
+    pub camera_entity: Option<Entity>,
+    pub mode: CameraMode,
+    pub lens: CameraLens,
+    /// Lens last written to the camera, so edits to `lens` are applied once.
+    pub applied_lens: Option<CameraLens>,
+
+    pub min_distance: f32,
+    pub max_distance: f32,
+    pub min_pitch: f32,
+    pub max_pitch: f32,
+    /// Angle inside each pitch limit over which look input fades out, in radians.
+    pub soft_pitch_zone: f32,
+    /// Mouse look rate, in radians per pixel of motion.
+    pub look_sensitivity: f32,
+    /// Distance change per wheel notch.
+    pub zoom_sensitivity: f32,
+    /// Eased wheel zoom in flight.
+    pub zoom_tween: Option<ZoomTween>,
+    pub zoom_mode: ZoomMode,
+    /// Distances, nearest first, that the wheel steps between instead of zooming freely; stepping
+    /// in past the nearest one enters first person. Empty zooms continuously.
+    pub zoom_presets: Vec<f32>,
+    /// Smooth-scroll travel towards the next zoom preset, in notches.
+    pub preset_scroll: f32,
+    /// Field of view `ZoomMode::FieldOfView` is heading for; taken from the camera when `None`.
+    pub target_fov: Option<f32>,
+    pub fov_velocity: f32,
+    /// Scales look speed by `CameraSettings::aim_sensitivity`.
+    pub aiming: bool,
+
+    pub head_offset: Vec3,
+    /// World-space shift of the orbit focus away from the pivot, set by panning.
+    pub focus_offset: Vec3,
+
+    pub shoulder_offset: Vec2,
+    /// Where the camera aims, right and up of the pivot in view space; the pivot sits off-centre
+    /// the opposite way.
+    pub framing_offset: Vec2,
+    pub left_shoulder: bool,
+    pub shoulder_side: f32,
+    pub shoulder_swap_speed: f32,
+
+    /// Radians about the view axis.
+    pub roll: f32,
+    pub roll_source: RollSource,
+    pub roll_speed: f32,
+    /// Ease roll back to the horizon while no roll key is held.
+    pub auto_level: bool,
+    pub level_rate: f32,
+
+    /// Camera distance from the pivot at which the player starts fading out.
+    pub player_fade_distance: f32,
+    /// Camera distance from the pivot at which the player is hidden.
+    pub player_hide_distance: f32,
+}
+
+impl Default for CameraRig {
+    fn default() -> Self {
+        Self {
+            yaw: 0.,
+            free_look_yaw: 0.,
+            free_look_return_rate: 6.,
+
+            camera_distance: 20.,
+            target_distance: 20.,
+            zoom_velocity: 0.,
+            collision_distance: None,
+            camera_pitch: 30.0f32.to_radians(),
+            camera_entity: None,
+            mode: CameraMode::Orbit,
+            lens: CameraLens::default(),
+            applied_lens: None,
+
+            min_distance: 5.,
+            max_distance: 30.,
+            min_pitch: 1f32.to_radians(),
+            max_pitch: 179f32.to_radians(),
+            soft_pitch_zone: 10f32.to_radians(),
+            look_sensitivity: 0.005,
+            zoom_sensitivity: 1.5,
+            zoom_tween: None,
+            zoom_mode: ZoomMode::default(),
+            zoom_presets: Vec::new(),
+            preset_scroll: 0.,
+            target_fov: None,
+            fov_velocity: 0.,
+            aiming: false,
+
+            head_offset: Vec3::new(0., 0.4, 0.),
+            focus_offset: Vec3::ZERO,
+
+            shoulder_offset: Vec2::ZERO,
+            framing_offset: Vec2::ZERO,
+            left_shoulder: false,
+            shoulder_side: 1.,
+            shoulder_swap_speed: 4.,
+
+            roll: 0.,
+            roll_source: RollSource::Off,
+            roll_speed: 90f32.to_radians(),
+            auto_level: true,
+            level_rate: 3.,
+
+            player_fade_distance: 2.,
+            player_hide_distance: 0.8,
+        }
+    }
+}
+
+impl CameraRig {
+    /// Vertical field of view of the rig's camera, if it has a perspective projection.
+    pub fn camera_fov(&self, projections: &Query<&Projection>) -> Option<f32> {
+        match projections.get(self.camera_entity?).ok()? {
+            Projection::Perspective(perspective) => Some(perspective.fov),
+            _ => None,
+        }
+    }
+
+    /// Yaw that points the orbit's view along `direction` in the horizontal plane.
+    pub fn yaw_towards(direction: Vec3) -> f32 {
+        (-direction.x).atan2(direction.z)
+    }
+
+    /// Moves the target distance by a wheel step, which eased smoothing animates.
+    pub fn zoom_by(&mut self, delta: f32) {
+        self.target_distance = (self.target_distance + delta).clamp(self.min_distance, self.max_distance);
+        self.zoom_tween = Some(ZoomTween {
+            from: self.camera_distance,
+            to: self.target_distance,
+            elapsed: 0.,
+        });
+    }
+
+    /// Tilts by `delta`, resisting more the deeper it pushes into the soft zone near a limit.
+    pub fn pitch_by(&mut self, delta: f32) {
+        let room = if delta > 0. {
+            self.max_pitch - self.camera_pitch
+        } else {
+            self.camera_pitch - self.min_pitch
+        };
+
+        let scale = if self.soft_pitch_zone > 0. && room < self.soft_pitch_zone {
+            // Keep a little input alive so the hard limit is still reachable.
+            smoothstep((room / self.soft_pitch_zone).max(0.)).max(0.05)
+        } else {
+            1.
+        };
+        self.camera_pitch = (self.camera_pitch + delta * scale).clamp(self.min_pitch, self.max_pitch);
+    }
+
+    /// Index of the zoom preset closest to the target distance.
+    pub fn nearest_zoom_preset(&self) -> Option<usize> {
+        self.zoom_presets
+            .iter()
+            .enumerate()
+            .min_by(|a, b| (a.1 - self.target_distance).abs().total_cmp(&(b.1 - self.target_distance).abs()))
+            .map(|(index, _)| index)
+    }
+
+    /// Scales whatever the zoom mode changes, for pinch gestures; below 1 zooms in.
+    pub fn scale_zoom(&mut self, factor: f32) {
+        match self.zoom_mode {
+            ZoomMode::Distance => self.target_distance *= factor,
+            ZoomMode::FieldOfView(zoom) => {
+                self.target_fov = self.target_fov.map(|fov| (fov * factor).clamp(zoom.min_fov, zoom.max_fov));
+            }
+        }
+    }
+
+    /// Holds the zoom input made since `target_distance` was `before` inside the spring arm's
+    /// limit: zooming out stops at it, and zooming in starts from it rather than from a target the
+    /// camera can't reach.
+    pub fn limit_zoom_input(&mut self, before: f32) {
+        let Some(limit) = self.collision_distance else { return };
+        let delta = self.target_distance - before;
+        if delta == 0. {
+            return;
+        }
+
+        let limit = limit.max(self.min_distance);
+        let from = before.min(limit);
+        self.target_distance = if delta > 0. { (from + delta).min(limit) } else { from + delta };
+        if let Some(tween) = self.zoom_tween.as_mut() {
+            tween.to = self.target_distance;
+        }
+    }
+
+    /// Jumps straight to `distance`, dropping any zoom in flight.
+    pub fn set_distance(&mut self, distance: f32) {
+        self.camera_distance = distance;
+        self.target_distance = distance;
+        self.zoom_velocity = 0.;
+        self.zoom_tween = None;
+    }
+
+    pub fn advance_zoom(&mut self, smoothing: ZoomSmoothing, delta_seconds: f32) {
+        self.target_distance = self.target_distance.clamp(self.min_distance, self.max_distance);
+
+        match smoothing {
+            ZoomSmoothing::Eased { easing, duration } => {
+                let Some(mut tween) = self.zoom_tween.filter(|tween| tween.to == self.target_distance) else {
+                    // Continuous input moves the target every frame, so track it directly.
+                    self.zoom_tween = None;
+                    self.camera_distance = self.target_distance;
+                    return;
+                };
+                tween.elapsed += delta_seconds;
+
+                let t = if duration > 0. { tween.elapsed / duration } else { 1. };
+                self.camera_distance = tween.from.lerp(tween.to, easing.apply(t));
+                self.zoom_tween = if t < 1. { Some(tween) } else { None };
+            }
+            ZoomSmoothing::CriticallyDamped { smooth_time } => {
+                self.zoom_tween = None;
+                self.camera_distance = smooth_damp(
+                    self.camera_distance,
+                    self.target_distance,
+                    &mut self.zoom_velocity,
+                    smooth_time,
+                    delta_seconds,
+                );
+            }
+        }
+    }
+
+    /// Look-speed multiplier from the settings for this rig's mode, aim and field of view.
+    pub fn look_scale(&self, settings: &CameraSettings, projections: &Query<&Projection>) -> f32 {
+        settings.look_scale(self.mode, self.camera_fov(projections), self.aiming)
+    }
+
+    /// Camera pose relative to the pivot for the pivot-following modes.
+    pub fn local_camera_transform(&self) -> Transform {
+        let orbit_pos = Vec3::new(0., self.camera_pitch.cos(), -self.camera_pitch.sin()) * self.camera_distance;
+        let look_dir = -orbit_pos;
+        let roll = Quat::from_rotation_z(self.roll);
+
+        if self.mode == CameraMode::FirstPerson {
+            let mut transform = Transform::from_translation(self.head_offset).looking_to(look_dir, Vec3::Y);
+            transform.rotation *= roll;
+            return transform;
+        }
+
+        let right = Vec3::Y.cross(orbit_pos).normalize_or_zero();
+        let side = 2. * smoothstep((self.shoulder_side + 1.) / 2.) - 1.;
+        let shoulder = right * self.shoulder_offset.x * side + Vec3::Y * self.shoulder_offset.y;
+
+        let aim = right * self.framing_offset.x + Vec3::Y * self.framing_offset.y;
+        let mut transform = Transform::from_translation(orbit_pos + shoulder).looking_to(look_dir + aim, Vec3::Y);
+        transform.rotation *= roll;
+        transform
+    }
+}
+
+/// Copies edits to `CameraRig::lens` onto the camera's projection.
+pub fn sync_camera_lens(
+    mut rigs: Query<&mut CameraRig>,
+    mut projections: Query<&mut Projection>,
+) {
+    for mut rig in rigs.iter_mut() {
+        if rig.applied_lens == Some(rig.lens) {
+            continue;
+        }
+        let Some(mut projection) = rig.camera_entity.and_then(|camera| projections.get_mut(camera).ok()) else {
+            continue;
+        };
+        rig.lens.apply(rig.applied_lens, &mut projection);
+        rig.applied_lens = Some(rig.lens);
+    }
+}
+
+/// Eases lenses towards the field of view picked by `ZoomMode::FieldOfView`.
+pub fn update_fov_zoom(
+    time: Res<Time>,
+    mut rigs: Query<&mut CameraRig>,
+    mut projections: Query<&mut Projection>,
+) {
+    for mut rig in rigs.iter_mut() {
+        let ZoomMode::FieldOfView(zoom) = rig.zoom_mode else {
+            rig.target_fov = None;
+            continue;
+        };
+        let Some(mut projection) = rig.camera_entity.and_then(|entity| projections.get_mut(entity).ok()) else {
+            continue;
+        };
+        let Projection::Perspective(perspective) = projection.as_mut() else {
+            continue;
+        };
+
+        let target = rig.target_fov.unwrap_or(perspective.fov).clamp(zoom.min_fov, zoom.max_fov);
+        rig.target_fov = Some(target);
+        perspective.fov = smooth_damp(perspective.fov, target, &mut rig.fov_velocity, zoom.smooth_time, time.delta_secs());
+    }
+}
+
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn update_camera (
+    time: Res<Time>,
+    settings: Res<CameraSettings>,
+    actions: ActionInput,
+    modal: Res<ModalActions>,
+    #[cfg(feature = "gamepad")]
+    gamepad: Res<GamepadAxes>,
+    mut rigs: Query<(
+        Entity,
+        &mut CameraRig,
+        &mut Transform,
+        &mut CameraModeState,
+        Option<&ActiveFixedCamera>,
+        Option<&SideScroller>,
+        Option<&InputSource>,
+        Has<CameraTarget>,
+        Has<TargetGroup>,
+    )>,
+    viewpoints: Query<&GlobalTransform, With<FixedCamera>>,
+    mut query: Query<&mut Player>
+) {
+    let mut keys = Vec2::ZERO;
+    if actions.pressed(CameraAction::MoveForward) { keys.y += 1.; }
+    if actions.pressed(CameraAction::MoveBack) { keys.y -= 1.; }
+    if actions.pressed(CameraAction::MoveRight) { keys.x += 1.; }
+    if actions.pressed(CameraAction::MoveLeft) { keys.x -= 1.; }
+
+    keys = keys.normalize_or_zero();
+
+    // Pivot poses of the rigs moving a player this frame, for `Player::rig` to pick from.
+    let mut poses = Vec::new();
+
+    for (entity, mut rig, mut transform, mut state, active_fixed, side_scroller, source, targeted, grouped) in rigs.iter_mut() {
+        let targeted = targeted || grouped;
+        let free_look = modal.active_for(CameraAction::FreeLook, source);
+
+        let movement = if accepts_keyboard_mouse(source) { keys } else { Vec2::ZERO };
+        #[cfg(feature = "gamepad")]
+        let movement = (movement + gamepad.for_source(source).movement).clamp_length_max(1.);
+        let movement = movement * time.delta_secs() * settings.move_speed;
+
+        let basis = match rig.mode {
+            CameraMode::Fixed => active_fixed
+                .and_then(|active| active.0)
+                .and_then(|entity| viewpoints.get(entity).ok())
+                .map(|viewpoint| {
+                    let fwd = viewpoint.forward().with_y(0.).normalize_or_zero();
+                    (fwd, fwd.cross(Vec3::Y))
+                }),
+            CameraMode::SideScroller => side_scroller.map(|side_scroller| (Vec3::ZERO, side_scroller.axis)),
+            CameraMode::Rail | CameraMode::TwinStick => {
+                let fwd = state.desired.forward().with_y(0.).normalize_or_zero();
+                Some((fwd, fwd.cross(Vec3::Y)))
+            }
+            _ => None,
+        };
+
+        if matches!(rig.mode, CameraMode::Fixed | CameraMode::SideScroller | CameraMode::Rail | CameraMode::TwinStick) {
+            if let Some((fwd, right)) = basis {
+                let step = fwd * movement.y + right * movement.x;
+
+                transform.translation += step;
+                if step != Vec3::ZERO {
+                    transform.look_to(step, Vec3::Y);
+                }
+            }
+
+            poses.push((entity, transform.translation, transform.rotation));
+            continue;
+        }
+
+        if !rig.mode.follows_pivot() {
+            continue;
+        }
+
+        rig.yaw = wrap_angle(rig.yaw);
+        rig.camera_pitch = rig.camera_pitch.clamp(rig.min_pitch, rig.max_pitch);
+        rig.camera_distance = rig.camera_distance.clamp(rig.min_distance, rig.max_distance);
+        rig.target_distance = rig.target_distance.clamp(rig.min_distance, rig.max_distance);
+
+        let target_side = if rig.left_shoulder { -1. } else { 1. };
+        let side_step = rig.shoulder_swap_speed * time.delta_secs();
+        rig.shoulder_side += (target_side - rig.shoulder_side).clamp(-side_step, side_step);
+
+        // The camera sits on the pivot's -Z side looking along +Z, so the pivot's back is ahead.
+        let fwd = *transform.back();
+        let right = fwd.cross(Vec3::Y);
+        let fwd = fwd * movement.y;
+        let right = right * movement.x;
+
+        // A targeted or grouped pivot is placed by its own system and leaves the player alone.
+        if !targeted {
+            transform.translation += fwd + right;
+        }
+        transform.rotation = Quat::from_rotation_y(-rig.yaw);
+
+        if !free_look && rig.free_look_yaw != 0. {
+            rig.free_look_yaw = wrap_angle(rig.free_look_yaw) * (-rig.free_look_return_rate * time.delta_secs()).exp();
+            if rig.free_look_yaw.abs() < 1e-4 {
+                rig.free_look_yaw = 0.;
+            }
+        }
+
+        let local = rig.local_camera_transform();
+        let heading = *transform * local;
+        let look = Transform::from_translation(transform.translation)
+            .with_rotation(Quat::from_rotation_y(-(rig.yaw + rig.free_look_yaw)));
+
+        state.desired = look * local;
+        if rig.mode != CameraMode::FirstPerson {
+            state.desired.translation += rig.focus_offset;
+        }
+
+        if !targeted {
+            poses.push((entity, transform.translation, heading.rotation));
+        }
+    }
+
+    let delta_seconds = time.delta_secs();
+    for mut player in query.iter_mut() {
+        let pose = match player.rig {
+            Some(rig) => poses.iter().find(|(entity, ..)| *entity == rig),
+            None => poses.last(),
+        };
+        let Some(&(_, pos_translation, pos_rotation)) = pose else {
+            player.velocity = Vec3::ZERO;
+            continue;
+        };
+
+        if delta_seconds > 0. {
+            player.velocity = (pos_translation - player.pos_translation) / delta_seconds;
+        }
+        player.pos_translation = pos_translation;
+        player.pos_rotation = pos_rotation;
+    }
+}
+
+/// A player drawn with a translucent copy of its material while the camera is close.
+#[derive(Component)]
+pub struct FadedPlayer(pub MaterialFade);
+
+#[allow(clippy::type_complexity)]
+pub fn update_player_visibility(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    rigs: Query<(Entity, &CameraRig, &Transform, &CameraModeState)>,
+    mut players: Query<(
+        Entity,
+        &Player,
+        &mut Visibility,
+        Option<&mut MeshMaterial3d<StandardMaterial>>,
+        Option<&FadedPlayer>,
+    )>,
+) {
+    let opacities: Vec<_> = rigs
+        .iter()
+        .map(|(entity, rig, pivot, state)| {
+            let opacity = if state.weight_of(CameraMode::FirstPerson) > 0.5 {
+                0.
+            } else if !rig.mode.follows_pivot() {
+                1.
+            } else {
+                let distance = state.current().translation.distance(pivot.translation);
+                let range = (rig.player_fade_distance - rig.player_hide_distance).max(f32::EPSILON);
+                ((distance - rig.player_hide_distance) / range).clamp(0., 1.)
+            };
+            (entity, opacity)
+        })
+        .collect();
+
+    for (entity, player, mut visibility, material, faded) in players.iter_mut() {
+        // A player fades for its own rig; one without a rig fades for whichever is nearest.
+        let opacity = opacities
+            .iter()
+            .filter(|(rig, _)| player.follows(*rig))
+            .map(|(_, opacity)| *opacity)
+            .fold(1., f32::min);
+        visibility.set_if_neq(if opacity <= 0. { Visibility::Hidden } else { Visibility::Inherited });
+
+        let Some(mut material) = material else {
+            continue;
+        };
+
+        match faded {
+            Some(faded) if opacity >= 1. => {
+                faded.0.restore(&mut materials, &mut material.0);
+                commands.entity(entity).remove::<FadedPlayer>();
+            }
+            Some(faded) => faded.0.set_opacity(&mut materials, &material.0, opacity),
+            None if opacity < 1. => {
+                if let Some(fade) = MaterialFade::begin(&mut materials, &mut material.0, AlphaMode::Blend, opacity) {
+                    commands.entity(entity).insert(FadedPlayer(fade));
+                }
+            }
+            None => {}
+        }
+    }
+}
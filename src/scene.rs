@@ -0,0 +1,49 @@
+use bevy::prelude::*;
+
+#[cfg(feature = "collision")]
+use crate::collision::CameraCollider;
+use crate::{
+    focus::Focusable,
+    lock_on::LockOnTarget,
+    player::Player,
+    spectator::Spectatable,
+};
+
+pub fn spawn_environment(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>
+) {
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(5.0, 5.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.7, 0.3, 0.0))),
+        #[cfg(feature = "collision")]
+        CameraCollider,
+    ));
+
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(1., 2., 1.))),
+        MeshMaterial3d(materials.add(Color::srgb(0.5, 0.5, 0.5))),
+        Transform::from_xyz(-1.5, 1., 1.5),
+        Name::new("Pillar"),
+        Focusable,
+        LockOnTarget,
+        Spectatable,
+        #[cfg(feature = "collision")]
+        CameraCollider,
+    ));
+
+    commands.spawn((
+        PointLight::default(),
+        Transform::from_xyz(4.0, 5.0, 4.0),
+    ));
+
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::from_length(1.))),
+        MeshMaterial3d(materials.add(Color::srgb(1.0, 1.0, 1.0))),
+        Transform::from_xyz(0., 0.5, 0.),
+        Name::new("Player"),
+        Player::default(),
+        Spectatable,
+    ));
+}
@@ -0,0 +1,299 @@
+use bevy::{
+    prelude::*,
+    utils::HashMap,
+};
+
+use crate::{
+    easing::Easing,
+    input_map::CameraAction,
+    mode::CameraMode,
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ResponseCurve {
+    #[default]
+    Linear,
+    Squared,
+    Exponent(f32),
+}
+
+impl ResponseCurve {
+    pub fn apply(self, x: f32) -> f32 {
+        match self {
+            ResponseCurve::Linear => x,
+            ResponseCurve::Squared => x * x,
+            ResponseCurve::Exponent(exponent) => x.powf(exponent),
+        }
+    }
+}
+
+#[cfg(feature = "gamepad")]
+#[derive(Clone, Copy, Debug)]
+pub struct StickSettings {
+    /// Deflection below which the whole stick reads as centred.
+    pub radial_deadzone: f32,
+    /// Deflection below which a single axis reads as zero.
+    pub axial_deadzone: f32,
+    pub curve: ResponseCurve,
+}
+
+#[cfg(feature = "gamepad")]
+impl Default for StickSettings {
+    fn default() -> Self {
+        Self {
+            radial_deadzone: 0.1,
+            axial_deadzone: 0.05,
+            curve: ResponseCurve::Squared,
+        }
+    }
+}
+
+#[cfg(feature = "gamepad")]
+impl StickSettings {
+    pub fn apply(&self, raw: Vec2) -> Vec2 {
+        let axial = |value: f32| {
+            let magnitude = value.abs();
+            if magnitude < self.axial_deadzone {
+                0.
+            } else {
+                value.signum() * (magnitude - self.axial_deadzone) / (1. - self.axial_deadzone).max(f32::EPSILON)
+            }
+        };
+        let stick = Vec2::new(axial(raw.x), axial(raw.y));
+
+        let magnitude = stick.length().min(1.);
+        if magnitude < self.radial_deadzone {
+            return Vec2::ZERO;
+        }
+        let scaled = (magnitude - self.radial_deadzone) / (1. - self.radial_deadzone).max(f32::EPSILON);
+        stick.normalize_or_zero() * self.curve.apply(scaled)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ZoomSmoothing {
+    /// Each wheel notch runs along the curve for a fixed time.
+    Eased { easing: Easing, duration: f32 },
+    /// Glides towards the target distance like a critically damped spring.
+    CriticallyDamped { smooth_time: f32 },
+}
+
+impl Default for ZoomSmoothing {
+    fn default() -> Self {
+        ZoomSmoothing::CriticallyDamped { smooth_time: 0.15 }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum LookSmoothing {
+    /// Raw mouse deltas.
+    #[default]
+    None,
+    /// Exponential moving average; higher rates follow the mouse more closely.
+    Exponential { rate: f32 },
+    /// Double-exponential smoothing, which also tracks the trend so steady motion lags less.
+    DoubleExponential { rate: f32, trend_rate: f32 },
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Activation {
+    /// Active while the binding is held.
+    #[default]
+    Hold,
+    /// Each press flips the action on or off.
+    Toggle,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LookMode {
+    /// The cursor stays grabbed until Escape or focus loss; click to grab it again.
+    #[default]
+    AlwaysLocked,
+    /// Mouse look only while the look button is held.
+    HoldToLook,
+}
+
+#[derive(Resource)]
+pub struct CameraSettings {
+    pub move_speed: f32,
+    /// Half-life of the player's turn towards the camera heading, in seconds; 0 turns instantly.
+    pub player_turn_half_life: f32,
+    pub invert_zoom: bool,
+    /// Zoom the top-down and turntable views towards the point under the cursor, keeping it still on screen.
+    pub zoom_to_cursor: bool,
+    /// How `camera_distance` moves towards `target_distance`.
+    pub zoom_smoothing: ZoomSmoothing,
+    /// Zoom rate of the zoom keys, in units per second.
+    pub key_zoom_speed: f32,
+    /// Pixel-based wheels (touchpads) count this many pixels as one notch.
+    pub scroll_pixels_per_notch: f32,
+    /// Factor applied to the free-fly speed per modified scroll notch.
+    pub scroll_speed_step: f32,
+
+    /// Field of view change per modified scroll notch, in radians.
+    pub fov_scroll_step: f32,
+    pub min_fov: f32,
+    pub max_fov: f32,
+
+    /// Mouse look multipliers for yaw and pitch, on top of the rig's own sensitivity.
+    pub mouse_sensitivity: Vec2,
+    pub invert_x: bool,
+    pub invert_y: bool,
+    pub look_smoothing: LookSmoothing,
+    pub look_mode: LookMode,
+    /// Hold or toggle for each of `MODAL_ACTIONS`; missing entries hold.
+    pub modal_activation: HashMap<CameraAction, Activation>,
+    /// Look-speed multipliers per camera mode; modes without an entry use 1.
+    pub mode_sensitivity: HashMap<CameraMode, f32>,
+    /// Look-speed multiplier while a rig is aiming.
+    pub aim_sensitivity: f32,
+    /// Slow look speed in proportion to how far the field of view narrows below `reference_fov`.
+    pub scale_look_with_fov: bool,
+    pub reference_fov: f32,
+    /// Yaw and pitch rates of the keyboard orbit keys, in radians per second.
+    pub key_orbit_speed: Vec2,
+    /// Time taken to fly to a recalled bookmark, in seconds.
+    pub bookmark_duration: f32,
+    pub bookmark_easing: Easing,
+    /// Longest gap between the clicks of a double-click, in seconds.
+    pub double_click_time: f32,
+    /// Room left around an entity framed by `FrameEntity`, as a multiple of its bounding radius.
+    pub frame_margin: f32,
+    /// Elevation `FrameEntity` views an entity from in the orbiting modes, in radians.
+    pub frame_elevation: f32,
+    /// Lets `HeadBob` rigs bob; a comfort option players often turn off.
+    #[cfg(feature = "shake")]
+    pub head_bob: bool,
+    /// Multiplier on every `HeadBob` amplitude.
+    #[cfg(feature = "shake")]
+    pub head_bob_scale: f32,
+    /// Middle-drag pan, as a fraction of the camera distance per pixel.
+    pub pan_sensitivity: f32,
+
+    /// One-finger orbit rate, in radians per pixel.
+    pub touch_look_sensitivity: f32,
+    /// Two-finger pan, as a fraction of the camera distance per pixel.
+    pub touch_pan_sensitivity: f32,
+
+    pub fly_speed: f32,
+    pub min_fly_speed: f32,
+    pub max_fly_speed: f32,
+
+    pub top_down_tilt: f32,
+    pub top_down_height: f32,
+    pub top_down_min_height: f32,
+    pub top_down_max_height: f32,
+    pub top_down_pan_speed: f32,
+    pub top_down_zoom_speed: f32,
+    pub top_down_rotate_speed: f32,
+    pub edge_scroll: bool,
+    /// Width of the window border that scrolls the top-down view, in logical pixels.
+    pub edge_scroll_width: f32,
+    /// Maps how far into the border the cursor is (0 to 1) onto the fraction of pan speed.
+    pub edge_scroll_ramp: ResponseCurve,
+
+    /// Right-stick look rate at full deflection, in radians per second.
+    #[cfg(feature = "gamepad")]
+    pub gamepad_look_sensitivity: f32,
+    /// Trigger and D-pad zoom rate, in units per second.
+    #[cfg(feature = "gamepad")]
+    pub gamepad_zoom_speed: f32,
+    #[cfg(feature = "gamepad")]
+    pub left_stick: StickSettings,
+    #[cfg(feature = "gamepad")]
+    pub right_stick: StickSettings,
+}
+
+impl Default for CameraSettings {
+    fn default() -> Self {
+        Self {
+            move_speed: 10.,
+            player_turn_half_life: 0.2,
+            invert_zoom: false,
+            zoom_to_cursor: true,
+            zoom_smoothing: ZoomSmoothing::default(),
+            key_zoom_speed: 10.,
+            scroll_pixels_per_notch: 50.,
+            scroll_speed_step: 1.1,
+
+            fov_scroll_step: 2f32.to_radians(),
+            min_fov: 20f32.to_radians(),
+            max_fov: 100f32.to_radians(),
+
+            mouse_sensitivity: Vec2::ONE,
+            invert_x: false,
+            invert_y: false,
+            look_smoothing: LookSmoothing::None,
+            look_mode: LookMode::AlwaysLocked,
+            modal_activation: HashMap::from_iter([
+                (CameraAction::FreeLook, Activation::Hold),
+                (CameraAction::Aim, Activation::Hold),
+                (CameraAction::ToggleFirstPerson, Activation::Toggle),
+                (CameraAction::ToggleFreeFly, Activation::Toggle),
+            ]),
+            mode_sensitivity: HashMap::default(),
+            aim_sensitivity: 0.6,
+            scale_look_with_fov: true,
+            reference_fov: std::f32::consts::FRAC_PI_4,
+            key_orbit_speed: Vec2::new(120f32.to_radians(), 60f32.to_radians()),
+            bookmark_duration: 0.6,
+            bookmark_easing: Easing::SmoothStep,
+            double_click_time: 0.3,
+            frame_margin: 1.2,
+            frame_elevation: 25f32.to_radians(),
+            #[cfg(feature = "shake")]
+            head_bob: true,
+            #[cfg(feature = "shake")]
+            head_bob_scale: 1.,
+            pan_sensitivity: 0.002,
+
+            touch_look_sensitivity: 0.005,
+            touch_pan_sensitivity: 0.002,
+
+            fly_speed: 10.,
+            min_fly_speed: 1.,
+            max_fly_speed: 100.,
+
+            top_down_tilt: 60f32.to_radians(),
+            top_down_height: 20.,
+            top_down_min_height: 5.,
+            top_down_max_height: 60.,
+            top_down_pan_speed: 15.,
+            top_down_zoom_speed: 2.,
+            top_down_rotate_speed: 90f32.to_radians(),
+            edge_scroll: true,
+            edge_scroll_width: 10.,
+            edge_scroll_ramp: ResponseCurve::Linear,
+
+            #[cfg(feature = "gamepad")]
+            gamepad_look_sensitivity: 3.,
+            #[cfg(feature = "gamepad")]
+            gamepad_zoom_speed: 15.,
+            #[cfg(feature = "gamepad")]
+            left_stick: StickSettings {
+                curve: ResponseCurve::Linear,
+                ..default()
+            },
+            #[cfg(feature = "gamepad")]
+            right_stick: StickSettings::default(),
+        }
+    }
+}
+
+impl CameraSettings {
+    pub fn activation(&self, action: CameraAction) -> Activation {
+        self.modal_activation.get(&action).copied().unwrap_or_default()
+    }
+
+    /// Look-speed multiplier for a rig in `mode` viewing through `fov`.
+    pub fn look_scale(&self, mode: CameraMode, fov: Option<f32>, aiming: bool) -> f32 {
+        let mut scale = self.mode_sensitivity.get(&mode).copied().unwrap_or(1.);
+        if aiming {
+            scale *= self.aim_sensitivity;
+        }
+        if let Some(fov) = fov.filter(|_| self.scale_look_with_fov) {
+            scale *= (fov / 2.).tan() / (self.reference_fov / 2.).tan();
+        }
+        scale
+    }
+}
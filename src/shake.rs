@@ -0,0 +1,815 @@
+use bevy::prelude::*;
+
+use crate::{
+    angle::angle_delta,
+    easing::{
+        smooth_damp,
+        smoothstep,
+    },
+    follow::Grounded,
+    input_map::{
+        ActionInput,
+        CameraAction,
+    },
+    mode::{
+        CameraMode,
+        CameraModeState,
+    },
+    player::{
+        Player,
+        rig_velocity,
+    },
+    rig::{
+        CameraRig,
+        ZoomMode,
+    },
+    settings::CameraSettings,
+};
+
+/// Offsets the shake layers build up over a frame, applied on top of the camera's pose and then
+/// cleared, so the rig never sees them.
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct ShakeOffset {
+    /// Pitch, yaw and roll, in radians.
+    pub rotation: Vec3,
+    /// Camera-space translation.
+    pub translation: Vec3,
+}
+
+impl ShakeOffset {
+    pub fn add(&mut self, rotation: Vec3, translation: Vec3) {
+        self.rotation += rotation;
+        self.translation += translation;
+    }
+}
+
+fn hash(cell: i32, seed: u32) -> f32 {
+    let mut x = (cell as u32).wrapping_mul(0x27d4_eb2d) ^ seed.wrapping_mul(0x1656_67b1);
+    x ^= x >> 15;
+    x = x.wrapping_mul(0x85eb_ca6b);
+    x ^= x >> 13;
+    x as f32 / u32::MAX as f32 * 2. - 1.
+}
+
+/// One-dimensional Perlin noise, smooth and roughly within -1 to 1, with a different curve per `seed`.
+pub fn perlin(t: f32, seed: u32) -> f32 {
+    let cell = t.floor();
+    let f = t - cell;
+    let cell = cell as i32;
+
+    let a = hash(cell, seed) * f;
+    let b = hash(cell + 1, seed) * (f - 1.);
+    let fade = f * f * f * (f * (f * 6. - 15.) + 10.);
+    // The raw noise peaks at half the gradient.
+    2. * (a + (b - a) * fade)
+}
+
+/// Slow noise on the camera's rotation, like footage shot by hand.
+#[derive(Component, Clone, Copy, Debug)]
+#[require(ShakeOffset)]
+pub struct HandheldSway {
+    /// Largest pitch, yaw and roll offsets, in radians.
+    pub amplitude: Vec3,
+    /// Noise cycles per second.
+    pub frequency: f32,
+    /// How quickly `weight` follows `target_weight`, per second.
+    pub fade_rate: f32,
+
+    pub weight: f32,
+    pub target_weight: f32,
+    pub time: f32,
+}
+
+impl Default for HandheldSway {
+    fn default() -> Self {
+        Self {
+            amplitude: Vec3::new(0.6, 0.8, 0.4).map(f32::to_radians),
+            frequency: 0.35,
+            fade_rate: 2.,
+
+            weight: 1.,
+            target_weight: 1.,
+            time: 0.,
+        }
+    }
+}
+
+impl HandheldSway {
+    /// Eases the sway in or out; 0 stills the camera.
+    pub fn fade_to(&mut self, weight: f32) {
+        self.target_weight = weight.clamp(0., 1.);
+    }
+}
+
+/// How one kind of shake feels: how hard it hits, how fast it rattles and how long it lasts.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShakeProfile {
+    /// Trauma added at the source.
+    pub trauma: f32,
+    /// Seconds the trauma holds before it starts to decay.
+    pub sustain: f32,
+    /// Trauma lost per second.
+    pub decay: f32,
+    /// Pitch, yaw and roll at full trauma, in radians.
+    pub max_rotation: Vec3,
+    /// Camera-space offset at full trauma.
+    pub max_translation: Vec3,
+    /// Noise cycles per second.
+    pub frequency: f32,
+    /// Distance from the source at which it fades to nothing.
+    pub radius: f32,
+}
+
+/// Ready-made shakes to use in place of tuning a `ShakeProfile`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ShakePreset {
+    Explosion,
+    EarthquakeRumble,
+    EngineVibration,
+    FootstepThud,
+}
+
+impl ShakePreset {
+    pub fn profile(self) -> ShakeProfile {
+        match self {
+            ShakePreset::Explosion => ShakeProfile {
+                trauma: 0.9,
+                sustain: 0.,
+                decay: 1.,
+                max_rotation: Vec3::new(6., 5., 8.).map(f32::to_radians),
+                max_translation: Vec3::new(0.3, 0.3, 0.1),
+                frequency: 18.,
+                radius: 40.,
+            },
+            ShakePreset::EarthquakeRumble => ShakeProfile {
+                trauma: 0.6,
+                sustain: 3.,
+                decay: 0.25,
+                max_rotation: Vec3::new(1.5, 1., 2.).map(f32::to_radians),
+                max_translation: Vec3::new(0.1, 0.2, 0.1),
+                frequency: 4.,
+                radius: 500.,
+            },
+            ShakePreset::EngineVibration => ShakeProfile {
+                trauma: 0.4,
+                sustain: 0.5,
+                decay: 2.,
+                max_rotation: Vec3::new(0.3, 0.2, 0.3).map(f32::to_radians),
+                max_translation: Vec3::new(0.02, 0.03, 0.),
+                frequency: 40.,
+                radius: 10.,
+            },
+            ShakePreset::FootstepThud => ShakeProfile {
+                trauma: 0.5,
+                sustain: 0.,
+                decay: 4.,
+                max_rotation: Vec3::new(1., 0.2, 0.3).map(f32::to_radians),
+                max_translation: Vec3::new(0., 0.08, 0.),
+                frequency: 10.,
+                radius: 15.,
+            },
+        }
+    }
+}
+
+impl From<ShakePreset> for ShakeProfile {
+    fn from(preset: ShakePreset) -> Self {
+        preset.profile()
+    }
+}
+
+/// A profiled shake playing on a `CameraShake`, alongside its own trauma.
+#[derive(Clone, Copy, Debug)]
+pub struct PlayingShake {
+    pub profile: ShakeProfile,
+    pub trauma: f32,
+    pub elapsed: f32,
+    pub time: f32,
+}
+
+/// Trauma-driven shake: impulses add trauma, which decays over time, and the shake grows with its
+/// square so small knocks stay subtle.
+#[derive(Component, Clone, Debug)]
+#[require(ShakeOffset)]
+pub struct CameraShake {
+    /// 0 to 1.
+    pub trauma: f32,
+    /// Trauma lost per second.
+    pub decay: f32,
+    /// Pitch, yaw and roll at full trauma, in radians.
+    pub max_rotation: Vec3,
+    /// Camera-space offset at full trauma.
+    pub max_translation: Vec3,
+    /// Noise cycles per second.
+    pub frequency: f32,
+    pub time: f32,
+    /// Profiled shakes from presets, each decaying on its own terms.
+    pub playing: Vec<PlayingShake>,
+}
+
+impl Default for CameraShake {
+    fn default() -> Self {
+        Self {
+            trauma: 0.,
+            decay: 1.2,
+            max_rotation: Vec3::new(4., 4., 6.).map(f32::to_radians),
+            max_translation: Vec3::new(0.15, 0.15, 0.05),
+            frequency: 15.,
+            time: 0.,
+            playing: Vec::new(),
+        }
+    }
+}
+
+impl CameraShake {
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).clamp(0., 1.);
+    }
+
+    /// Starts `profile` at `scale` of its trauma.
+    pub fn play(&mut self, profile: impl Into<ShakeProfile>, scale: f32) {
+        let profile = profile.into();
+        let trauma = (profile.trauma * scale).clamp(0., 1.);
+        if trauma > 0. {
+            self.playing.push(PlayingShake {
+                profile,
+                trauma,
+                elapsed: 0.,
+                time: self.playing.len() as f32 * 17.,
+            });
+        }
+    }
+}
+
+/// Shakes every `CameraShake` rig in reach of `position`, harder the closer its camera is.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct ShakeImpulse {
+    pub position: Vec3,
+    /// Trauma added to a camera at `position`, or the share of the profile's own trauma.
+    pub strength: f32,
+    /// Distance at which the impulse fades to nothing.
+    pub radius: f32,
+    /// Plays this shake instead of adding to the rig's own trauma.
+    pub profile: Option<ShakeProfile>,
+}
+
+impl ShakeImpulse {
+    pub fn new(position: Vec3, strength: f32, radius: f32) -> Self {
+        Self {
+            position,
+            strength,
+            radius,
+            profile: None,
+        }
+    }
+
+    /// Plays `preset` at full strength out to its own radius.
+    pub fn preset(preset: ShakePreset, position: Vec3) -> Self {
+        let profile = preset.profile();
+        Self {
+            position,
+            strength: 1.,
+            radius: profile.radius,
+            profile: Some(profile),
+        }
+    }
+}
+
+/// Share of an impulse's strength felt `distance` away, falling off smoothly to 0 at `radius`.
+pub fn impulse_falloff(distance: f32, radius: f32) -> f32 {
+    if radius <= 0. {
+        return 0.;
+    }
+    let reach = (1. - distance / radius).clamp(0., 1.);
+    reach * reach
+}
+
+pub fn receive_shake_impulses(
+    mut events: EventReader<ShakeImpulse>,
+    mut rigs: Query<(&CameraRig, &mut CameraShake)>,
+    cameras: Query<&GlobalTransform>,
+) {
+    for impulse in events.read() {
+        for (rig, mut shake) in rigs.iter_mut() {
+            let Some(camera) = rig.camera_entity.and_then(|camera| cameras.get(camera).ok()) else {
+                continue;
+            };
+            let distance = camera.translation().distance(impulse.position);
+            let strength = impulse.strength * impulse_falloff(distance, impulse.radius);
+            match impulse.profile {
+                Some(profile) => shake.play(profile, strength),
+                None => shake.add_trauma(strength),
+            }
+        }
+    }
+}
+
+/// Rotation and translation of a shake at noise time `t`.
+fn shake_at(t: f32, trauma: f32, max_rotation: Vec3, max_translation: Vec3) -> (Vec3, Vec3) {
+    let amount = trauma * trauma;
+    let noise = |seed: u32| Vec3::new(perlin(t, seed), perlin(t, seed + 1), perlin(t, seed + 2));
+    (noise(10) * max_rotation * amount, noise(20) * max_translation * amount)
+}
+
+pub fn update_camera_shake(
+    time: Res<Time>,
+    mut rigs: Query<(&mut CameraShake, &mut ShakeOffset)>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for (mut shake, mut offset) in rigs.iter_mut() {
+        if shake.trauma > 0. {
+            shake.time += delta_seconds * shake.frequency;
+            let (rotation, translation) = shake_at(shake.time, shake.trauma, shake.max_rotation, shake.max_translation);
+            offset.add(rotation, translation);
+
+            shake.trauma = (shake.trauma - shake.decay * delta_seconds).max(0.);
+        }
+
+        for playing in shake.playing.iter_mut() {
+            let profile = playing.profile;
+            playing.time += delta_seconds * profile.frequency;
+            let (rotation, translation) = shake_at(playing.time, playing.trauma, profile.max_rotation, profile.max_translation);
+            offset.add(rotation, translation);
+
+            playing.elapsed += delta_seconds;
+            if playing.elapsed > profile.sustain {
+                playing.trauma = (playing.trauma - profile.decay * delta_seconds).max(0.);
+            }
+        }
+        shake.playing.retain(|playing| playing.trauma > 0.);
+    }
+}
+
+/// Kicks the camera of `rig` up by `pitch` and right by `yaw` radians, as from a weapon firing.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct RecoilKick {
+    pub rig: Entity,
+    pub pitch: f32,
+    pub yaw: f32,
+}
+
+/// Recoil kicks layered over the view and eased back out, leaving the rig's own yaw and pitch,
+/// and so look input, untouched.
+#[derive(Component, Clone, Copy, Debug)]
+#[require(ShakeOffset)]
+pub struct Recoil {
+    /// Roughly how long the view takes to settle after a kick, in seconds.
+    pub recovery_time: f32,
+    /// Largest pitch and yaw repeated kicks can build up to, in radians.
+    pub max_offset: Vec2,
+
+    /// Pitch and yaw still to recover from.
+    pub offset: Vec2,
+    pub velocity: Vec2,
+}
+
+impl Default for Recoil {
+    fn default() -> Self {
+        Self {
+            recovery_time: 0.25,
+            max_offset: Vec2::new(15., 10.).map(f32::to_radians),
+
+            offset: Vec2::ZERO,
+            velocity: Vec2::ZERO,
+        }
+    }
+}
+
+impl Recoil {
+    pub fn kick(&mut self, pitch: f32, yaw: f32) {
+        self.offset = (self.offset + Vec2::new(pitch, yaw)).clamp(-self.max_offset, self.max_offset);
+    }
+}
+
+pub fn receive_recoil_kicks(
+    mut events: EventReader<RecoilKick>,
+    mut rigs: Query<&mut Recoil>,
+) {
+    for event in events.read() {
+        if let Ok(mut recoil) = rigs.get_mut(event.rig) {
+            recoil.kick(event.pitch, event.yaw);
+        }
+    }
+}
+
+pub fn update_recoil(
+    time: Res<Time>,
+    mut rigs: Query<(&mut Recoil, &mut ShakeOffset)>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for (mut recoil, mut offset) in rigs.iter_mut() {
+        let Recoil { recovery_time, offset: mut kick, mut velocity, .. } = *recoil;
+        kick.x = smooth_damp(kick.x, 0., &mut velocity.x, recovery_time, delta_seconds);
+        kick.y = smooth_damp(kick.y, 0., &mut velocity.y, recovery_time, delta_seconds);
+        recoil.offset = kick;
+        recoil.velocity = velocity;
+
+        // Yaw turns left for positive angles, so a kick to the right is negative.
+        offset.add(Vec3::new(kick.x, -kick.y, 0.), Vec3::ZERO);
+    }
+}
+
+/// Dips the camera when the pivot's `Grounded` turns true after a fall, deeper for harder landings.
+#[derive(Component, Clone, Copy, Debug)]
+#[require(ShakeOffset)]
+pub struct LandingDip {
+    /// Landing speed below which nothing happens.
+    pub min_speed: f32,
+    /// Drop per unit of landing speed above `min_speed`.
+    pub amplitude: f32,
+    pub max_depth: f32,
+    /// Downward tilt per unit of drop, in radians.
+    pub tilt: f32,
+    /// Length of the dip, down and back up, in seconds.
+    pub duration: f32,
+
+    pub depth: f32,
+    pub elapsed: Option<f32>,
+    /// Downward speed last frame while airborne.
+    pub fall_speed: f32,
+    pub airborne: bool,
+}
+
+impl Default for LandingDip {
+    fn default() -> Self {
+        Self {
+            min_speed: 3.,
+            amplitude: 0.02,
+            max_depth: 0.4,
+            tilt: 0.15,
+            duration: 0.35,
+
+            depth: 0.,
+            elapsed: None,
+            fall_speed: 0.,
+            airborne: false,
+        }
+    }
+}
+
+impl LandingDip {
+    /// Starts a dip for a landing at `speed`, as the movement code would report it.
+    pub fn land(&mut self, speed: f32) {
+        let depth = ((speed - self.min_speed) * self.amplitude).min(self.max_depth);
+        if depth > 0. {
+            self.depth = depth;
+            self.elapsed = Some(0.);
+        }
+    }
+
+    /// Drop at this point of the dip: a quick fall and a slower ease back up.
+    pub fn offset(&self) -> f32 {
+        let Some(elapsed) = self.elapsed else { return 0. };
+        let t = if self.duration > 0. { (elapsed / self.duration).min(1.) } else { 1. };
+        let shape = if t < 0.25 { smoothstep(t / 0.25) } else { 1. - smoothstep((t - 0.25) / 0.75) };
+        self.depth * shape
+    }
+}
+
+/// Knocks the view of `rig` away from a hit travelling along the world-space `direction`, from the
+/// attacker towards the player; `strength` runs from 0 to 1.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct HitReaction {
+    pub rig: Entity,
+    pub direction: Vec3,
+    pub strength: f32,
+}
+
+/// The tilt left by `HitReaction`s, easing back to the rig's own view.
+#[derive(Component, Clone, Copy, Debug)]
+#[require(ShakeOffset)]
+pub struct HitTilt {
+    /// Pitch and roll of a full-strength hit, in radians.
+    pub max_angle: f32,
+    /// Share of `max_angle` the view also turns away by.
+    pub yaw_share: f32,
+    /// Roughly how long the view takes to settle, in seconds.
+    pub recovery_time: f32,
+
+    /// Pitch, yaw and roll still to recover from.
+    pub offset: Vec3,
+    pub velocity: Vec3,
+}
+
+impl Default for HitTilt {
+    fn default() -> Self {
+        Self {
+            max_angle: 6f32.to_radians(),
+            yaw_share: 0.5,
+            recovery_time: 0.35,
+
+            offset: Vec3::ZERO,
+            velocity: Vec3::ZERO,
+        }
+    }
+}
+
+pub fn receive_hit_reactions(
+    mut events: EventReader<HitReaction>,
+    mut rigs: Query<(&CameraModeState, &mut HitTilt)>,
+) {
+    for event in events.read() {
+        let Ok((state, mut tilt)) = rigs.get_mut(event.rig) else { continue };
+        let Some(local) = (state.current().rotation.inverse() * event.direction).try_normalize() else {
+            continue;
+        };
+
+        // A hit from the front travels towards the camera and tips it back; one from the left
+        // pushes it over and round to the right.
+        let angle = tilt.max_angle * event.strength.clamp(0., 1.);
+        let kick = Vec3::new(local.z, -local.x * tilt.yaw_share, -local.x) * angle;
+        tilt.offset = (tilt.offset + kick).clamp(Vec3::splat(-tilt.max_angle), Vec3::splat(tilt.max_angle));
+    }
+}
+
+pub fn update_hit_tilt(
+    time: Res<Time>,
+    mut rigs: Query<(&mut HitTilt, &mut ShakeOffset)>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for (mut tilt, mut offset) in rigs.iter_mut() {
+        let HitTilt { recovery_time, offset: mut angles, mut velocity, .. } = *tilt;
+        angles.x = smooth_damp(angles.x, 0., &mut velocity.x, recovery_time, delta_seconds);
+        angles.y = smooth_damp(angles.y, 0., &mut velocity.y, recovery_time, delta_seconds);
+        angles.z = smooth_damp(angles.z, 0., &mut velocity.z, recovery_time, delta_seconds);
+        tilt.offset = angles;
+        tilt.velocity = velocity;
+
+        offset.add(angles, Vec3::ZERO);
+    }
+}
+
+pub fn update_landing_dip(
+    time: Res<Time>,
+    players: Query<&Player>,
+    mut rigs: Query<(Entity, &Grounded, &mut LandingDip, &mut ShakeOffset)>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for (entity, grounded, mut dip, mut offset) in rigs.iter_mut() {
+        if !grounded.0 {
+            dip.fall_speed = -rig_velocity(&players, entity).y;
+            dip.airborne = true;
+        } else if dip.airborne {
+            dip.airborne = false;
+            let speed = dip.fall_speed;
+            dip.land(speed);
+            dip.fall_speed = 0.;
+        }
+
+        let Some(elapsed) = dip.elapsed else { continue };
+        let drop = dip.offset();
+        offset.add(Vec3::new(-drop * dip.tilt, 0., 0.), Vec3::NEG_Y * drop);
+
+        let elapsed = elapsed + delta_seconds;
+        dip.elapsed = (elapsed < dip.duration).then_some(elapsed);
+    }
+}
+
+/// A slight roll into strafes and turns in the first- and third-person views, on top of the rig's
+/// own roll.
+#[derive(Component, Clone, Copy, Debug)]
+#[require(ShakeOffset)]
+pub struct MotionTilt {
+    /// Roll per unit of sideways speed, in radians.
+    pub strafe: f32,
+    /// Roll per radian per second of turning.
+    pub turn: f32,
+    pub max_roll: f32,
+    /// How quickly the roll follows the motion, per second.
+    pub stiffness: f32,
+
+    pub roll: f32,
+    pub last_yaw: Option<f32>,
+}
+
+impl Default for MotionTilt {
+    fn default() -> Self {
+        Self {
+            strafe: 0.4f32.to_radians(),
+            turn: 1f32.to_radians(),
+            max_roll: 3f32.to_radians(),
+            stiffness: 6.,
+
+            roll: 0.,
+            last_yaw: None,
+        }
+    }
+}
+
+pub fn update_motion_tilt(
+    time: Res<Time>,
+    players: Query<&Player>,
+    mut rigs: Query<(Entity, &CameraRig, &mut MotionTilt, &mut ShakeOffset)>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for (entity, rig, mut tilt, mut offset) in rigs.iter_mut() {
+        let yaw = rig.yaw + rig.free_look_yaw;
+        let velocity = rig_velocity(&players, entity);
+        let yaw_rate = match tilt.last_yaw {
+            Some(last_yaw) if delta_seconds > 0. => angle_delta(last_yaw, yaw) / delta_seconds,
+            _ => 0.,
+        };
+        tilt.last_yaw = Some(yaw);
+
+        let goal = if matches!(rig.mode, CameraMode::Orbit | CameraMode::FirstPerson) {
+            let right = Quat::from_rotation_y(-yaw) * Vec3::NEG_X;
+            // Positive roll turns anticlockwise, so leaning right is negative.
+            -(velocity.dot(right) * tilt.strafe + yaw_rate * tilt.turn).clamp(-tilt.max_roll, tilt.max_roll)
+        } else {
+            0.
+        };
+        let blend = 1. - (-tilt.stiffness * delta_seconds).exp();
+        tilt.roll = tilt.roll.lerp(goal, blend);
+
+        offset.add(Vec3::new(0., 0., tilt.roll), Vec3::ZERO);
+    }
+}
+
+/// First-person head bob, stepping in time with the pivot's speed.
+#[derive(Component, Clone, Copy, Debug)]
+#[require(ShakeOffset)]
+pub struct HeadBob {
+    /// Distance covered per step.
+    pub stride_length: f32,
+    /// Height of the bob each step.
+    pub vertical: f32,
+    /// Side-to-side sway over each pair of steps.
+    pub lateral: f32,
+    /// Speed at which the bob reaches full height.
+    pub full_speed: f32,
+    /// How quickly the bob fades in and out, per second.
+    pub fade_rate: f32,
+
+    /// Position through a pair of steps, in radians.
+    pub phase: f32,
+    pub weight: f32,
+}
+
+impl Default for HeadBob {
+    fn default() -> Self {
+        Self {
+            stride_length: 1.4,
+            vertical: 0.05,
+            lateral: 0.03,
+            full_speed: 5.,
+            fade_rate: 6.,
+
+            phase: 0.,
+            weight: 0.,
+        }
+    }
+}
+
+pub fn update_head_bob(
+    time: Res<Time>,
+    settings: Res<CameraSettings>,
+    players: Query<&Player>,
+    mut rigs: Query<(Entity, &CameraRig, &mut HeadBob, &mut ShakeOffset, Option<&Grounded>)>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for (entity, rig, mut bob, mut offset, grounded) in rigs.iter_mut() {
+        let speed = rig_velocity(&players, entity).with_y(0.).length();
+
+        let bobbing = settings.head_bob
+            && rig.mode == CameraMode::FirstPerson
+            && grounded.is_none_or(|grounded| grounded.0);
+        let goal = if bobbing && bob.full_speed > 0. { (speed / bob.full_speed).min(1.) } else { 0. };
+        bob.weight = bob.weight.lerp(goal, 1. - (-bob.fade_rate * delta_seconds).exp());
+        if bob.weight < 1e-3 {
+            continue;
+        }
+
+        if bob.stride_length > 0. {
+            bob.phase = (bob.phase + speed / (2. * bob.stride_length) * std::f32::consts::TAU * delta_seconds)
+                % std::f32::consts::TAU;
+        }
+        let scale = bob.weight * settings.head_bob_scale;
+        let sway = Vec3::new(
+            bob.phase.sin() * bob.lateral,
+            (2. * bob.phase).sin() * bob.vertical,
+            0.,
+        );
+        offset.add(Vec3::ZERO, sway * scale);
+    }
+}
+
+/// Slow breathing drift of the aim while the rig aims or zooms its lens in, which
+/// `CameraAction::HoldBreath` stills for a while.
+#[derive(Component, Clone, Copy, Debug)]
+#[require(ShakeOffset)]
+pub struct BreathingSway {
+    /// Largest pitch and yaw drift, in radians.
+    pub amplitude: Vec2,
+    /// Breaths per second.
+    pub rate: f32,
+    /// Longest the breath can be held, in seconds.
+    pub hold_duration: f32,
+    /// Seconds of breathing needed to hold the breath for the full duration again.
+    pub recovery_time: f32,
+    /// How quickly the sway fades in and out, per second.
+    pub fade_rate: f32,
+
+    pub phase: f32,
+    pub weight: f32,
+    /// Seconds of breath held so far, recovering while breathing.
+    pub held_for: f32,
+    pub holding: bool,
+}
+
+impl Default for BreathingSway {
+    fn default() -> Self {
+        Self {
+            amplitude: Vec2::new(0.3, 0.4).map(f32::to_radians),
+            rate: 0.25,
+            hold_duration: 3.,
+            recovery_time: 4.,
+            fade_rate: 5.,
+
+            phase: 0.,
+            weight: 0.,
+            held_for: 0.,
+            holding: false,
+        }
+    }
+}
+
+pub fn update_breathing_sway(
+    time: Res<Time>,
+    actions: ActionInput,
+    mut rigs: Query<(&CameraRig, &mut BreathingSway, &mut ShakeOffset)>,
+) {
+    let delta_seconds = time.delta_secs();
+    let hold = actions.pressed(CameraAction::HoldBreath);
+
+    for (rig, mut sway, mut offset) in rigs.iter_mut() {
+        let zoomed = match rig.zoom_mode {
+            ZoomMode::FieldOfView(zoom) => rig.target_fov.is_some_and(|fov| fov < zoom.max_fov),
+            ZoomMode::Distance => false,
+        };
+        let aiming = rig.aiming || zoomed;
+
+        // Letting go, or running out of breath, means breathing again before the next hold.
+        sway.holding = aiming && hold && (sway.holding || sway.held_for == 0.) && sway.held_for < sway.hold_duration;
+        if sway.holding {
+            sway.held_for += delta_seconds;
+        } else if sway.recovery_time > 0. {
+            sway.held_for = (sway.held_for - delta_seconds * sway.hold_duration / sway.recovery_time).max(0.);
+        } else {
+            sway.held_for = 0.;
+        }
+
+        let goal = if aiming && !sway.holding { 1. } else { 0. };
+        sway.weight = sway.weight.lerp(goal, 1. - (-sway.fade_rate * delta_seconds).exp());
+        sway.phase = (sway.phase + sway.rate * std::f32::consts::TAU * delta_seconds) % (2. * std::f32::consts::TAU);
+        if sway.weight < 1e-3 {
+            continue;
+        }
+
+        // Rise and fall with each breath, drifting sideways over two.
+        let drift = Vec2::new(sway.phase.sin(), (sway.phase / 2.).sin()) * sway.amplitude * sway.weight;
+        offset.add(Vec3::new(drift.x, drift.y, 0.), Vec3::ZERO);
+    }
+}
+
+pub fn update_handheld_sway(
+    time: Res<Time>,
+    mut rigs: Query<(&mut HandheldSway, &mut ShakeOffset)>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for (mut sway, mut offset) in rigs.iter_mut() {
+        let blend = 1. - (-sway.fade_rate * delta_seconds).exp();
+        sway.weight = sway.weight.lerp(sway.target_weight, blend);
+        if sway.weight < 1e-3 && sway.target_weight == 0. {
+            sway.weight = 0.;
+            continue;
+        }
+
+        sway.time += delta_seconds * sway.frequency;
+        let t = sway.time;
+        let noise = Vec3::new(perlin(t, 0), perlin(t, 1), perlin(t, 2));
+        offset.add(noise * sway.amplitude * sway.weight, Vec3::ZERO);
+    }
+}
+
+pub fn apply_shake_offset(
+    mut rigs: Query<(&CameraRig, &mut ShakeOffset)>,
+    mut cameras: Query<&mut Transform, Without<CameraRig>>,
+) {
+    for (rig, mut offset) in rigs.iter_mut() {
+        let ShakeOffset { rotation, translation } = std::mem::take(&mut *offset);
+        let Some(mut transform) = rig.camera_entity.and_then(|camera| cameras.get_mut(camera).ok()) else {
+            continue;
+        };
+
+        let shift = transform.rotation * translation;
+        transform.translation += shift;
+        transform.rotation *= Quat::from_euler(EulerRot::YXZ, rotation.y, rotation.x, rotation.z);
+    }
+}
@@ -0,0 +1,103 @@
+use bevy::prelude::*;
+
+use crate::{
+    input_map::{
+        ActionInput,
+        CameraAction,
+    },
+    mode::{
+        CameraMode,
+        CameraModeState,
+    },
+    player::{
+        Player,
+        rig_player,
+    },
+    rig::CameraRig,
+    split_screen::InputSource,
+};
+
+#[derive(Component)]
+pub struct SideScroller {
+    /// World-space direction the player moves along; the camera looks across it.
+    pub axis: Vec3,
+    pub distance: f32,
+    pub height: f32,
+    /// Half-size of the box, along `axis` and up, the target can move in without moving the camera.
+    pub dead_zone: Vec2,
+    pub focus: Vec3,
+}
+
+impl Default for SideScroller {
+    fn default() -> Self {
+        Self {
+            axis: Vec3::X,
+            distance: 12.,
+            height: 2.,
+            dead_zone: Vec2::new(1.5, 1.),
+            focus: Vec3::ZERO,
+        }
+    }
+}
+
+impl SideScroller {
+    pub fn following(focus: Vec3) -> Self {
+        Self {
+            focus,
+            ..default()
+        }
+    }
+
+    pub fn camera_normal(&self) -> Vec3 {
+        self.axis.cross(Vec3::Y).normalize_or(Vec3::Z)
+    }
+
+    pub fn follow(&mut self, target: Vec3) {
+        let delta = target - self.focus;
+        let normal = self.camera_normal();
+
+        let along = delta.dot(self.axis);
+        let excess_along = along - along.clamp(-self.dead_zone.x, self.dead_zone.x);
+        let excess_up = delta.y - delta.y.clamp(-self.dead_zone.y, self.dead_zone.y);
+
+        self.focus += self.axis * excess_along + Vec3::Y * excess_up + normal * delta.dot(normal);
+    }
+
+    pub fn camera_transform(&self) -> Transform {
+        let normal = self.camera_normal();
+        Transform::from_translation(self.focus + normal * self.distance + Vec3::Y * self.height)
+            .looking_to(-normal, Vec3::Y)
+    }
+}
+
+pub fn toggle_side_scroller(
+    mut commands: Commands,
+    actions: ActionInput,
+    mut query: Query<(Entity, &mut CameraRig, &Transform, Option<&InputSource>)>,
+) {
+    for (pivot, mut rig, transform, source) in query.iter_mut() {
+        if !actions.just_pressed_by(CameraAction::ToggleSideScroller, source) {
+            continue;
+        }
+        if rig.mode == CameraMode::SideScroller {
+            rig.mode = CameraMode::Orbit;
+        } else {
+            commands.entity(pivot).insert(SideScroller::following(transform.translation));
+            rig.mode = CameraMode::SideScroller;
+        }
+    }
+}
+
+pub fn update_side_scroller(
+    players: Query<(&Player, &Transform), Without<CameraRig>>,
+    mut rigs: Query<(Entity, &CameraRig, &mut SideScroller, &mut CameraModeState, &Transform)>,
+) {
+    for (entity, rig, mut side_scroller, mut state, pivot) in rigs.iter_mut() {
+        if rig.mode != CameraMode::SideScroller {
+            continue;
+        }
+        let player = rig_player(players.iter(), entity).map(|transform| transform.translation);
+        side_scroller.follow(player.unwrap_or(pivot.translation));
+        state.desired = side_scroller.camera_transform();
+    }
+}
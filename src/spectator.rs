@@ -0,0 +1,207 @@
+use bevy::prelude::*;
+
+use crate::{
+    cursor::CursorLook,
+    easing::Easing,
+    input::MouseDeltas,
+    input_map::{
+        ActionInput,
+        CameraAction,
+    },
+    mode::{
+        CameraMode,
+        CameraModeState,
+    },
+    rig::CameraRig,
+};
+
+/// An entity the free-fly camera can cycle to with `CameraAction::SpectateNext` and `SpectatePrevious`.
+/// Its `Name`, if any, is shown while it is being watched.
+#[derive(Component, Default)]
+pub struct Spectatable;
+
+/// Which `Spectatable` the free-fly camera is watching, and how it frames it.
+#[derive(Resource)]
+pub struct Spectator {
+    /// Distance kept from the target, measured across the ground.
+    pub distance: f32,
+    pub height: f32,
+    /// Time to fly to a new target, in seconds.
+    pub flight_duration: f32,
+    pub easing: Easing,
+
+    pub target: Option<Entity>,
+    /// Camera offset from the target while it is framed.
+    pub offset: Vec3,
+    flight: Option<SpectatorFlight>,
+    tracking: bool,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct SpectatorFlight {
+    from: Transform,
+    elapsed: f32,
+}
+
+impl Default for Spectator {
+    fn default() -> Self {
+        Self {
+            distance: 6.,
+            height: 2.,
+            flight_duration: 0.8,
+            easing: Easing::SmoothStep,
+
+            target: None,
+            offset: Vec3::ZERO,
+            flight: None,
+            tracking: false,
+        }
+    }
+}
+
+impl Spectator {
+    /// Whether the camera is still flying to or holding on the target; movement input lets go.
+    pub fn is_tracking(&self) -> bool {
+        self.tracking
+    }
+}
+
+/// Marks the text that names the spectated entity; spawned the first time there is one to name.
+#[derive(Component)]
+pub struct SpectatorLabel;
+
+#[allow(clippy::too_many_arguments)]
+pub fn update_spectator(
+    time: Res<Time>,
+    actions: ActionInput,
+    cursor: Res<CursorLook>,
+    deltas: Res<MouseDeltas>,
+    mut spectator: ResMut<Spectator>,
+    spectatables: Query<(Entity, &GlobalTransform), With<Spectatable>>,
+    mut rigs: Query<(&CameraRig, &mut CameraModeState)>,
+) {
+    let Some((_, mut state)) = rigs.iter_mut().find(|(rig, _)| rig.mode == CameraMode::FreeFly) else {
+        spectator.target = None;
+        spectator.flight = None;
+        spectator.tracking = false;
+        return;
+    };
+
+    let step = if actions.just_pressed(CameraAction::SpectateNext) {
+        1
+    } else if actions.just_pressed(CameraAction::SpectatePrevious) {
+        -1
+    } else {
+        0
+    };
+
+    if step != 0 {
+        let mut entities: Vec<Entity> = spectatables.iter().map(|(entity, _)| entity).collect();
+        entities.sort();
+
+        let next = match spectator.target.and_then(|target| entities.iter().position(|&entity| entity == target)) {
+            Some(index) => entities.get((index as isize + step).rem_euclid(entities.len() as isize) as usize),
+            None if step > 0 => entities.first(),
+            None => entities.last(),
+        };
+
+        if let Some(&next) = next {
+            let current = state.desired;
+            // Keep roughly the current heading, so flights read as a pan rather than a swing round.
+            let back = current.back().with_y(0.).normalize_or(Vec3::Z);
+            spectator.target = Some(next);
+            spectator.offset = back * spectator.distance + Vec3::Y * spectator.height;
+            spectator.flight = Some(SpectatorFlight { from: current, elapsed: 0. });
+            spectator.tracking = true;
+        }
+    }
+
+    let moving = [
+        CameraAction::MoveForward,
+        CameraAction::MoveBack,
+        CameraAction::MoveLeft,
+        CameraAction::MoveRight,
+        CameraAction::MoveUp,
+        CameraAction::MoveDown,
+    ]
+    .into_iter()
+    .any(|action| actions.pressed(action));
+    let looking = cursor.active && deltas.motion != Vec2::ZERO;
+    if moving || looking {
+        spectator.tracking = false;
+        spectator.flight = None;
+    }
+
+    if !spectator.tracking {
+        return;
+    }
+    let Some(target) = spectator.target.and_then(|target| spectatables.get(target).ok()) else {
+        spectator.target = None;
+        spectator.tracking = false;
+        return;
+    };
+
+    let focus = target.1.translation();
+    let framing = Transform::from_translation(focus + spectator.offset).looking_at(focus, Vec3::Y);
+
+    state.desired = match spectator.flight {
+        Some(mut flight) => {
+            flight.elapsed += time.delta_secs();
+            let t = if spectator.flight_duration > 0. {
+                flight.elapsed / spectator.flight_duration
+            } else {
+                1.
+            };
+            let eased = spectator.easing.apply(t);
+            spectator.flight = if t < 1. { Some(flight) } else { None };
+
+            Transform {
+                translation: flight.from.translation.lerp(framing.translation, eased),
+                rotation: flight.from.rotation.slerp(framing.rotation, eased),
+                scale: framing.scale,
+            }
+        }
+        None => framing,
+    };
+}
+
+pub fn update_spectator_label(
+    mut commands: Commands,
+    spectator: Res<Spectator>,
+    names: Query<&Name>,
+    mut labels: Query<(&mut Text, &mut Visibility), With<SpectatorLabel>>,
+) {
+    // Manual control lets go of the target, so the label goes with it.
+    let label = spectator
+        .target
+        .filter(|_| spectator.tracking)
+        .map(|target| names.get(target).map_or_else(|_| format!("{target}"), |name| name.as_str().to_owned()));
+
+    if labels.is_empty() {
+        if let Some(label) = label {
+            commands.spawn((
+                SpectatorLabel,
+                Text::new(label),
+                Node {
+                    position_type: PositionType::Absolute,
+                    bottom: Val::Px(16.),
+                    left: Val::Px(16.),
+                    ..default()
+                },
+            ));
+        }
+        return;
+    }
+
+    for (mut text, mut visibility) in labels.iter_mut() {
+        let Some(label) = &label else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+
+        if text.0 != *label {
+            text.0 = label.clone();
+        }
+        visibility.set_if_neq(Visibility::Inherited);
+    }
+}
@@ -0,0 +1,106 @@
+use bevy::{
+    prelude::*,
+    render::camera::Viewport,
+    window::PrimaryWindow,
+};
+
+use crate::rig::CameraRig;
+
+/// The device that drives a rig when several share the screen, hotkeys and mode toggles included;
+/// rigs without one take input from every device.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InputSource {
+    KeyboardMouse,
+    Gamepad(Entity),
+}
+
+/// Whether keyboard and mouse input moves a rig with `source`.
+pub fn accepts_keyboard_mouse(source: Option<&InputSource>) -> bool {
+    !matches!(source, Some(InputSource::Gamepad(_)))
+}
+
+/// Whether `gamepad` moves a rig with `source`.
+pub fn accepts_gamepad(source: Option<&InputSource>, gamepad: Entity) -> bool {
+    match source {
+        None => true,
+        Some(InputSource::KeyboardMouse) => false,
+        Some(InputSource::Gamepad(bound)) => *bound == gamepad,
+    }
+}
+
+/// Gives the rig's camera one pane of a split primary window. Panes are laid out in slot order:
+/// one fills the window, two sit side by side, and three or four share a two-by-two grid.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ScreenSlot(pub u32);
+
+pub const MAX_SCREEN_SLOTS: u32 = 4;
+
+/// Position and size of pane `index` of `count` in a window of `size` physical pixels.
+pub fn pane_rect(index: u32, count: u32, size: UVec2) -> (UVec2, UVec2) {
+    let (columns, rows) = match count {
+        0 | 1 => (1, 1),
+        2 => (2, 1),
+        _ => (2, 2),
+    };
+    let pane = UVec2::new(size.x / columns, size.y / rows).max(UVec2::ONE);
+    let cell = UVec2::new(index % columns, index / columns);
+    (cell * pane, pane)
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ScreenCorner {
+    TopLeft,
+    #[default]
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// Rectangle `size` pixels across tucked into `corner` of the area at `origin`, `margin` in from
+/// its edges.
+pub fn inset_rect(corner: ScreenCorner, size: UVec2, margin: u32, origin: UVec2, area: UVec2) -> (UVec2, UVec2) {
+    let size = size.min(area.saturating_sub(UVec2::splat(2 * margin))).max(UVec2::ONE);
+    let far = area.saturating_sub(size + UVec2::splat(margin));
+    let offset = match corner {
+        ScreenCorner::TopLeft => UVec2::splat(margin),
+        ScreenCorner::TopRight => UVec2::new(far.x, margin),
+        ScreenCorner::BottomLeft => UVec2::new(margin, far.y),
+        ScreenCorner::BottomRight => far,
+    };
+    (origin + offset, size)
+}
+
+pub fn layout_split_screen(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    rigs: Query<(&CameraRig, &ScreenSlot)>,
+    mut cameras: Query<&mut Camera>,
+) {
+    let Ok(window) = windows.get_single() else { return };
+    let size = window.physical_size();
+
+    let mut slots: Vec<_> = rigs.iter().filter(|(_, slot)| slot.0 < MAX_SCREEN_SLOTS).collect();
+    slots.sort_by_key(|(_, slot)| **slot);
+    let count = slots.len() as u32;
+
+    for (index, (rig, slot)) in slots.into_iter().enumerate() {
+        let Some(mut camera) = rig.camera_entity.and_then(|camera| cameras.get_mut(camera).ok()) else {
+            continue;
+        };
+        let (physical_position, physical_size) = pane_rect(index as u32, count, size);
+        // The projection's aspect ratio follows the viewport, so each pane keeps its proportions.
+        let viewport = Viewport {
+            physical_position,
+            physical_size,
+            ..default()
+        };
+
+        if camera.viewport.as_ref().is_none_or(|current| {
+            current.physical_position != viewport.physical_position || current.physical_size != viewport.physical_size
+        }) {
+            camera.viewport = Some(viewport);
+        }
+        if camera.order != slot.0 as isize {
+            camera.order = slot.0 as isize;
+        }
+    }
+}
@@ -0,0 +1,549 @@
+use bevy::prelude::*;
+
+use crate::{
+    angle::{
+        angle_delta,
+        lerp_angle,
+    },
+    easing::Easing,
+    rig::CameraRig,
+    settings::CameraSettings,
+};
+
+/// Local transforms and parents of everything a rig can follow.
+pub type TargetTransforms<'w, 's> = Query<'w, 's, (&'static Transform, Option<&'static Parent>), Without<CameraRig>>;
+
+/// World transform of `entity` from this frame's local transforms. `GlobalTransform` is only
+/// propagated after `Update`, so it runs a frame behind anything riding a moving parent.
+pub fn target_transform(entity: Entity, transforms: &TargetTransforms) -> Option<Transform> {
+    let (transform, mut parent) = transforms.get(entity).ok()?;
+    let mut world = *transform;
+    while let Some(ancestor) = parent {
+        let (ancestor_transform, next) = transforms.get(ancestor.get()).ok()?;
+        world = ancestor_transform.mul_transform(world);
+        parent = next;
+    }
+    Some(world)
+}
+
+/// What a rig does when its `CameraTarget` despawns or loses its `Transform`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TargetLostBehavior {
+    /// Keep the pivot where it is until the game retargets.
+    #[default]
+    Freeze,
+    /// Drop the `CameraTarget`, handing the pivot back to movement input.
+    Release,
+}
+
+/// Which of a rig's references stopped pointing at something usable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LostTargetKind {
+    /// `CameraRig::camera_entity`, which is cleared.
+    Camera,
+    /// The entity a `CameraTarget` follows.
+    Follow,
+    /// A `TargetGroup` member, which is removed from the group.
+    GroupMember,
+}
+
+/// Sent once when an entity a rig depends on is despawned or no longer has a `Transform`.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct CameraTargetLost {
+    pub rig: Entity,
+    pub target: Entity,
+    pub kind: LostTargetKind,
+}
+
+/// Orbit angles and distance a rig settles on around a new target.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TargetFraming {
+    pub yaw: f32,
+    pub pitch: f32,
+    pub distance: f32,
+}
+
+impl TargetFraming {
+    pub fn capture(rig: &CameraRig) -> Self {
+        Self {
+            yaw: rig.yaw,
+            pitch: rig.camera_pitch,
+            distance: rig.camera_distance,
+        }
+    }
+
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self {
+            yaw: lerp_angle(self.yaw, other.yaw, t),
+            pitch: self.pitch.lerp(other.pitch, t),
+            distance: self.distance.lerp(other.distance, t),
+        }
+    }
+}
+
+/// Binds a rig's pivot to another entity, so the camera follows it instead of movement input.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct CameraTarget {
+    pub entity: Entity,
+    /// Pivot offset from the target's origin, in world space.
+    pub offset: Vec3,
+    /// Time to glide from the previous framing to a new target, in seconds.
+    pub transition_duration: f32,
+    pub easing: Easing,
+    /// Turn the orbit with the target's parent, so riding a rotating platform carries the view round.
+    pub inherit_rotation: bool,
+    pub on_lost: TargetLostBehavior,
+
+    transition: Option<TargetTransition>,
+    parent_yaw: Option<f32>,
+    lost: bool,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct TargetTransition {
+    previous: Option<Entity>,
+    from: Vec3,
+    from_framing: TargetFraming,
+    to_framing: Option<TargetFraming>,
+    elapsed: f32,
+    announced: bool,
+}
+
+impl CameraTarget {
+    pub fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            offset: Vec3::ZERO,
+            transition_duration: 0.6,
+            easing: Easing::SmoothStep,
+            inherit_rotation: true,
+            on_lost: TargetLostBehavior::default(),
+
+            transition: None,
+            parent_yaw: None,
+            lost: false,
+        }
+    }
+
+    pub fn with_offset(mut self, offset: Vec3) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Switches to `entity`, gliding over from the pivot's current position `from` and the rig's
+    /// current framing. Without a `framing` the rig keeps its angles and distance.
+    pub fn retarget(&mut self, entity: Entity, from: Vec3, rig: &CameraRig, framing: Option<TargetFraming>) {
+        let previous = (self.entity != entity || self.transition.is_some()).then_some(self.entity);
+        self.entity = entity;
+        self.transition = Some(TargetTransition {
+            previous,
+            from,
+            from_framing: TargetFraming::capture(rig),
+            to_framing: framing,
+            elapsed: 0.,
+            announced: false,
+        });
+        self.parent_yaw = None;
+        self.lost = false;
+    }
+
+    /// Whether the followed entity has gone and the rig is frozen waiting for a new one.
+    pub fn is_lost(&self) -> bool {
+        self.lost
+    }
+
+    pub fn is_transitioning(&self) -> bool {
+        self.transition.is_some()
+    }
+}
+
+/// Points a rig at a new target, or with `None` hands the pivot back to movement input.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct SetCameraTarget {
+    pub rig: Entity,
+    pub target: Option<Entity>,
+    /// Framing to ease into around the new target; `None` keeps the current one.
+    pub framing: Option<TargetFraming>,
+}
+
+/// Sent when a rig starts easing towards a new target.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct TargetTransitionStarted {
+    pub rig: Entity,
+    pub from: Option<Entity>,
+    pub to: Entity,
+}
+
+/// Sent when a rig has settled on its new target.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct TargetTransitionFinished {
+    pub rig: Entity,
+    pub target: Entity,
+}
+
+pub fn apply_set_camera_target(
+    mut commands: Commands,
+    mut events: EventReader<SetCameraTarget>,
+    mut rigs: Query<(&CameraRig, &Transform, Option<&mut CameraTarget>)>,
+) {
+    for event in events.read() {
+        let Ok((rig, transform, target)) = rigs.get_mut(event.rig) else {
+            continue;
+        };
+
+        match (event.target, target) {
+            (Some(entity), Some(mut target)) => target.retarget(entity, transform.translation, rig, event.framing),
+            (Some(entity), None) => {
+                let mut target = CameraTarget::new(entity);
+                target.retarget(entity, transform.translation, rig, event.framing);
+                commands.entity(event.rig).insert(target);
+            }
+            (None, Some(_)) => {
+                commands.entity(event.rig).remove::<CameraTarget>();
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+pub fn follow_camera_target(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut lost: EventWriter<CameraTargetLost>,
+    mut started: EventWriter<TargetTransitionStarted>,
+    mut finished: EventWriter<TargetTransitionFinished>,
+    targets: TargetTransforms,
+    mut rigs: Query<(Entity, &mut CameraRig, &mut Transform, &mut CameraTarget)>,
+) {
+    for (entity, mut rig, mut transform, mut target) in rigs.iter_mut() {
+        let Some(world) = target_transform(target.entity, &targets) else {
+            if !target.lost {
+                target.lost = true;
+                target.transition = None;
+                lost.send(CameraTargetLost {
+                    rig: entity,
+                    target: target.entity,
+                    kind: LostTargetKind::Follow,
+                });
+                if target.on_lost == TargetLostBehavior::Release {
+                    commands.entity(entity).remove::<CameraTarget>();
+                }
+            }
+            continue;
+        };
+        target.lost = false;
+        let goal = world.translation + target.offset;
+
+        let parent_yaw = targets
+            .get(target.entity)
+            .ok()
+            .and_then(|(_, parent)| target_transform(parent?.get(), &targets))
+            .map(|parent| parent.rotation.to_euler(EulerRot::YXZ).0);
+        if let (true, Some(previous), Some(current)) = (target.inherit_rotation, target.parent_yaw, parent_yaw) {
+            // The orbit turns opposite to its yaw, as the pivot faces `-yaw`.
+            rig.yaw -= angle_delta(previous, current);
+        }
+        target.parent_yaw = parent_yaw;
+
+        let Some(mut transition) = target.transition else {
+            transform.translation = goal;
+            continue;
+        };
+
+        if !transition.announced {
+            transition.announced = true;
+            started.send(TargetTransitionStarted {
+                rig: entity,
+                from: transition.previous,
+                to: target.entity,
+            });
+        }
+
+        transition.elapsed += time.delta_secs();
+        let t = if target.transition_duration > 0. {
+            transition.elapsed / target.transition_duration
+        } else {
+            1.
+        };
+        let eased = target.easing.apply(t);
+        transform.translation = transition.from.lerp(goal, eased);
+
+        if let Some(to) = transition.to_framing {
+            let framing = transition.from_framing.lerp(&to, eased);
+            rig.yaw = framing.yaw;
+            rig.camera_pitch = framing.pitch;
+            rig.set_distance(framing.distance);
+        }
+
+        target.transition = if t < 1. {
+            Some(transition)
+        } else {
+            finished.send(TargetTransitionFinished {
+                rig: entity,
+                target: target.entity,
+            });
+            None
+        };
+    }
+}
+
+/// How a `TargetGroup` keeps its bounding sphere in view.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GroupFraming {
+    /// Pull the rig's distance in and out.
+    #[default]
+    Distance,
+    /// Keep the distance and widen or narrow the field of view.
+    FieldOfView,
+}
+
+/// One entity framed by a `TargetGroup`.
+#[derive(Clone, Copy, Debug)]
+pub struct GroupMember {
+    pub entity: Entity,
+    /// Pull on the group's centre. Below 1 the member also shrinks out of the bounds, so animating
+    /// it to zero drops the member without a jump.
+    pub weight: f32,
+    /// Radius kept in view around the member, so bodies are framed rather than origins.
+    pub radius: f32,
+}
+
+impl GroupMember {
+    pub fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            weight: 1.,
+            radius: 1.,
+        }
+    }
+}
+
+/// Centres the pivot on a weighted set of entities and keeps all of them on screen.
+#[derive(Component, Clone, Debug)]
+pub struct TargetGroup {
+    pub members: Vec<GroupMember>,
+    /// Margin on the bounding sphere, as a multiplier.
+    pub padding: f32,
+    pub framing: GroupFraming,
+    /// How quickly the pivot and framing settle, per second.
+    pub stiffness: f32,
+}
+
+impl Default for TargetGroup {
+    fn default() -> Self {
+        Self {
+            members: Vec::new(),
+            padding: 1.2,
+            framing: GroupFraming::default(),
+            stiffness: 5.,
+        }
+    }
+}
+
+impl TargetGroup {
+    pub fn with_member(mut self, entity: Entity, weight: f32, radius: f32) -> Self {
+        self.members.push(GroupMember { entity, weight, radius });
+        self
+    }
+
+    pub fn member_mut(&mut self, entity: Entity) -> Option<&mut GroupMember> {
+        self.members.iter_mut().find(|member| member.entity == entity)
+    }
+
+    /// Weighted centroid and the radius that encloses every member's sphere around it.
+    pub fn bounds(&self, targets: &TargetTransforms) -> Option<(Vec3, f32)> {
+        let members: Vec<(Vec3, &GroupMember)> = self.members
+            .iter()
+            .filter(|member| member.weight > 0.)
+            .filter_map(|member| Some((target_transform(member.entity, targets)?.translation, member)))
+            .collect();
+
+        let total: f32 = members.iter().map(|(_, member)| member.weight).sum();
+        if total <= 0. {
+            return None;
+        }
+        let centre = members.iter().map(|(position, member)| *position * member.weight).sum::<Vec3>() / total;
+
+        let radius = members
+            .iter()
+            .map(|(position, member)| {
+                let influence = member.weight.min(1.);
+                (position.distance(centre) + member.radius) * influence
+            })
+            .fold(0., f32::max);
+
+        Some((centre, radius))
+    }
+}
+
+pub fn frame_target_group(
+    time: Res<Time>,
+    settings: Res<CameraSettings>,
+    targets: TargetTransforms,
+    mut lost: EventWriter<CameraTargetLost>,
+    mut rigs: Query<(Entity, &mut CameraRig, &mut Transform, &mut TargetGroup)>,
+    mut projections: Query<&mut Projection>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for (entity, mut rig, mut transform, mut group) in rigs.iter_mut() {
+        group.members.retain(|member| {
+            let found = targets.contains(member.entity);
+            if !found {
+                lost.send(CameraTargetLost {
+                    rig: entity,
+                    target: member.entity,
+                    kind: LostTargetKind::GroupMember,
+                });
+            }
+            found
+        });
+
+        let Some((centre, radius)) = group.bounds(&targets) else {
+            continue;
+        };
+        let blend = 1. - (-group.stiffness * delta_seconds).exp();
+        transform.translation = transform.translation.lerp(centre, blend);
+
+        let Some(mut projection) = rig.camera_entity.and_then(|entity| projections.get_mut(entity).ok()) else {
+            continue;
+        };
+        let Projection::Perspective(perspective) = projection.as_mut() else {
+            continue;
+        };
+
+        // The narrower of the two axes decides whether the sphere fits.
+        let half_vertical = perspective.fov / 2.;
+        let half_horizontal = (half_vertical.tan() * perspective.aspect_ratio).atan();
+        let radius = radius * group.padding;
+
+        match group.framing {
+            GroupFraming::Distance => {
+                let half_angle = half_vertical.min(half_horizontal);
+                rig.target_distance = radius / half_angle.sin();
+            }
+            GroupFraming::FieldOfView => {
+                let half_angle = (radius / rig.camera_distance.max(radius)).asin();
+                let vertical = if perspective.aspect_ratio < 1. {
+                    // Portrait: fit horizontally and derive the vertical angle from it.
+                    2. * (half_angle.tan() / perspective.aspect_ratio).atan()
+                } else {
+                    2. * half_angle
+                };
+                let fov = vertical.clamp(settings.min_fov, settings.max_fov);
+                perspective.fov = perspective.fov.lerp(fov, blend);
+            }
+        }
+    }
+}
+
+/// Clears camera entities that have despawned or are no longer cameras.
+pub fn check_camera_entities(
+    mut lost: EventWriter<CameraTargetLost>,
+    cameras: Query<(), With<Camera>>,
+    mut rigs: Query<(Entity, &mut CameraRig)>,
+) {
+    for (entity, mut rig) in rigs.iter_mut() {
+        let Some(camera) = rig.camera_entity else { continue };
+        if cameras.contains(camera) {
+            continue;
+        }
+
+        rig.camera_entity = None;
+        lost.send(CameraTargetLost {
+            rig: entity,
+            target: camera,
+            kind: LostTargetKind::Camera,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use super::*;
+
+    const EPSILON: f32 = 1e-4;
+
+    /// A platform at `platform`, a child target standing `offset` from its centre, and a rig
+    /// following the child; `GlobalTransform`s are never propagated.
+    fn setup(platform: Transform, offset: Vec3) -> (App, Entity, Entity, Entity) {
+        let mut app = App::new();
+        app.init_resource::<Time>();
+        app.add_event::<CameraTargetLost>();
+        app.add_event::<TargetTransitionStarted>();
+        app.add_event::<TargetTransitionFinished>();
+        app.add_systems(Update, follow_camera_target);
+
+        let platform = app.world_mut().spawn(platform).id();
+        let child = app.world_mut().spawn(Transform::from_translation(offset)).set_parent(platform).id();
+        let rig = app.world_mut().spawn((CameraRig::default(), Transform::default(), CameraTarget::new(child))).id();
+        (app, platform, child, rig)
+    }
+
+    fn pivot(app: &App, rig: Entity) -> Vec3 {
+        app.world().get::<Transform>(rig).unwrap().translation
+    }
+
+    #[test]
+    fn follows_child_of_moved_parent() {
+        let (mut app, platform, _, rig) = setup(Transform::from_xyz(10., 0., 0.), Vec3::new(0., 1., 2.));
+        app.update();
+        assert!(pivot(&app, rig).distance(Vec3::new(10., 1., 2.)) < EPSILON);
+
+        app.world_mut().get_mut::<Transform>(platform).unwrap().translation = Vec3::new(-4., 3., 0.);
+        app.update();
+        assert!(pivot(&app, rig).distance(Vec3::new(-4., 4., 2.)) < EPSILON);
+    }
+
+    #[test]
+    fn follows_child_of_rotating_parent() {
+        let (mut app, platform, _, rig) = setup(Transform::default(), Vec3::new(3., 0., 0.));
+        app.update();
+        assert!(pivot(&app, rig).distance(Vec3::new(3., 0., 0.)) < EPSILON);
+
+        // A quarter turn about +Y carries +X round to -Z.
+        app.world_mut().get_mut::<Transform>(platform).unwrap().rotation = Quat::from_rotation_y(FRAC_PI_2);
+        app.update();
+        assert!(pivot(&app, rig).distance(Vec3::new(0., 0., -3.)) < EPSILON);
+    }
+
+    #[test]
+    fn follows_grandchild_of_rotating_parents() {
+        let (mut app, platform, child, rig) = setup(Transform::from_xyz(0., 2., 0.), Vec3::new(1., 0., 0.));
+        let grandchild = app.world_mut().spawn(Transform::from_xyz(1., 0., 0.)).set_parent(child).id();
+        app.world_mut().get_mut::<CameraTarget>(rig).unwrap().entity = grandchild;
+
+        app.world_mut().get_mut::<Transform>(platform).unwrap().rotation = Quat::from_rotation_y(FRAC_PI_2);
+        app.world_mut().get_mut::<Transform>(child).unwrap().rotation = Quat::from_rotation_y(FRAC_PI_2);
+        app.update();
+        // The child sits at -Z from the platform; the grandchild's +X is turned a half turn to -X.
+        assert!(pivot(&app, rig).distance(Vec3::new(-1., 2., -1.)) < EPSILON);
+    }
+
+    #[test]
+    fn orbit_turns_with_rotating_parent() {
+        let (mut app, platform, _, rig) = setup(Transform::default(), Vec3::new(3., 0., 0.));
+        app.update();
+        let yaw = app.world().get::<CameraRig>(rig).unwrap().yaw;
+
+        app.world_mut().get_mut::<Transform>(platform).unwrap().rotation = Quat::from_rotation_y(0.3);
+        app.update();
+        let turned = app.world().get::<CameraRig>(rig).unwrap().yaw;
+        assert!((angle_delta(yaw, turned) + 0.3).abs() < EPSILON);
+    }
+
+    #[test]
+    fn orbit_ignores_parent_rotation_when_not_inherited() {
+        let (mut app, platform, _, rig) = setup(Transform::default(), Vec3::new(3., 0., 0.));
+        app.world_mut().get_mut::<CameraTarget>(rig).unwrap().inherit_rotation = false;
+        app.update();
+        let yaw = app.world().get::<CameraRig>(rig).unwrap().yaw;
+
+        app.world_mut().get_mut::<Transform>(platform).unwrap().rotation = Quat::from_rotation_y(0.3);
+        app.update();
+        assert_eq!(app.world().get::<CameraRig>(rig).unwrap().yaw, yaw);
+        assert!(pivot(&app, rig).distance(Quat::from_rotation_y(0.3) * Vec3::new(3., 0., 0.)) < EPSILON);
+    }
+}
@@ -0,0 +1,159 @@
+use std::f32::consts::PI;
+
+use bevy::{
+    prelude::*,
+    window::PrimaryWindow,
+};
+
+use crate::{
+    cursor::cursor_ray,
+    input::MouseDeltas,
+    input_map::{
+        ActionInput,
+        CameraAction,
+    },
+    mode::{
+        CameraMode,
+        CameraModeState,
+    },
+    rig::CameraRig,
+    settings::CameraSettings,
+    split_screen::{
+        InputSource,
+        accepts_keyboard_mouse,
+    },
+};
+
+#[derive(Component)]
+pub struct TopDown {
+    pub focus: Vec3,
+    pub height: f32,
+    pub yaw: f32,
+}
+
+impl TopDown {
+    pub fn camera_transform(&self, tilt: f32) -> Transform {
+        let back = self.height / tilt.tan();
+        let offset = Quat::from_rotation_y(self.yaw) * Vec3::new(0., self.height, back);
+        Transform::from_translation(self.focus + offset).looking_at(self.focus, Vec3::Y)
+    }
+}
+
+pub fn toggle_top_down(
+    mut commands: Commands,
+    settings: Res<CameraSettings>,
+    actions: ActionInput,
+    mut query: Query<(Entity, &mut CameraRig, &Transform, Option<&InputSource>)>,
+) {
+    for (pivot, mut rig, transform, source) in query.iter_mut() {
+        if !actions.just_pressed_by(CameraAction::ToggleTopDown, source) {
+            continue;
+        }
+        if rig.mode == CameraMode::TopDown {
+            rig.mode = CameraMode::Orbit;
+        } else {
+            commands.entity(pivot).insert(TopDown {
+                focus: transform.translation,
+                height: settings.top_down_height,
+                yaw: PI - rig.yaw,
+            });
+            rig.mode = CameraMode::TopDown;
+        }
+    }
+}
+
+/// Pan direction from the cursor's depth into the window border, scaled by the ramp.
+fn edge_scroll(settings: &CameraSettings, cursor: Vec2, size: Vec2) -> Vec2 {
+    let width = settings.edge_scroll_width;
+    if width <= 0. {
+        return Vec2::ZERO;
+    }
+
+    let depth = |distance: f32| settings.edge_scroll_ramp.apply((1. - distance / width).clamp(0., 1.));
+
+    Vec2::new(
+        depth(size.x - cursor.x) - depth(cursor.x),
+        depth(cursor.y) - depth(size.y - cursor.y),
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn update_top_down(
+    time: Res<Time>,
+    settings: Res<CameraSettings>,
+    actions: ActionInput,
+    deltas: Res<MouseDeltas>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut rigs: Query<(&CameraRig, &mut TopDown, &mut CameraModeState, Option<&InputSource>)>,
+) {
+    let mut wheel = deltas.scroll;
+    if actions.scroll_modified() {
+        wheel = 0.;
+    }
+
+    let mut edge = Vec2::ZERO;
+    if settings.edge_scroll && !actions.pressed(CameraAction::PauseEdgeScroll) {
+        if let Some((window, cursor)) = windows
+            .get_single()
+            .ok()
+            .filter(|window| window.focused)
+            .and_then(|window| Some((window, window.cursor_position()?)))
+        {
+            edge = edge_scroll(&settings, cursor, window.size());
+        }
+    }
+
+    let delta_seconds = time.delta_secs();
+
+    for (rig, mut top_down, mut state, source) in rigs.iter_mut() {
+        if rig.mode != CameraMode::TopDown {
+            continue;
+        }
+
+        let mut pan = Vec2::ZERO;
+        if actions.pressed_by(CameraAction::MoveForward, source) { pan.y += 1.; }
+        if actions.pressed_by(CameraAction::MoveBack, source) { pan.y -= 1.; }
+        if actions.pressed_by(CameraAction::MoveRight, source) { pan.x += 1.; }
+        if actions.pressed_by(CameraAction::MoveLeft, source) { pan.x -= 1.; }
+
+        pan = pan.normalize_or_zero();
+
+        // The wheel and the window edges belong to the mouse.
+        let mouse = accepts_keyboard_mouse(source);
+        if mouse {
+            pan += edge;
+        }
+        let scroll = if mouse { wheel } else { 0. };
+        let pan = pan.clamp_length_max(1.) * settings.top_down_pan_speed * delta_seconds;
+
+        let mut rotate = 0.;
+        if actions.pressed_by(CameraAction::RotateLeft, source) { rotate += 1.; }
+        if actions.pressed_by(CameraAction::RotateRight, source) { rotate -= 1.; }
+
+        top_down.yaw += rotate * settings.top_down_rotate_speed * delta_seconds;
+        let height = (top_down.height - scroll * settings.top_down_zoom_speed)
+            .clamp(settings.top_down_min_height, settings.top_down_max_height);
+        if settings.zoom_to_cursor && height != top_down.height {
+            let camera = rig.camera_entity.and_then(|camera| cameras.get(camera).ok());
+            let hovered = windows.get_single().ok().zip(camera)
+                .and_then(|(window, (camera, transform))| cursor_ray(window, camera, transform))
+                .and_then(|ray| {
+                    let distance = ray.intersect_plane(top_down.focus, InfinitePlane3d::new(Vec3::Y))?;
+                    Some(ray.get_point(distance))
+                });
+            // Scaling the view about the hovered ground point leaves that point where it was on screen.
+            if let Some(point) = hovered {
+                top_down.focus = point + (top_down.focus - point) * (height / top_down.height);
+            }
+        }
+        top_down.height = height;
+
+        let rotation = Quat::from_rotation_y(top_down.yaw);
+        let fwd = rotation * Vec3::NEG_Z;
+        let right = rotation * Vec3::X;
+        top_down.focus += fwd * pan.y + right * pan.x;
+
+        state.desired = top_down.camera_transform(settings.top_down_tilt);
+    }
+}
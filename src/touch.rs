@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+
+use crate::{
+    mode::{
+        CameraMode,
+        CameraModeState,
+    },
+    rig::CameraRig,
+    settings::CameraSettings,
+};
+
+pub fn process_touch_events(
+    settings: Res<CameraSettings>,
+    touches: Res<Touches>,
+    projections: Query<&Projection>,
+    mut query: Query<(&mut CameraRig, &CameraModeState)>,
+) {
+    let active: Vec<_> = touches.iter().take(3).collect();
+
+    let mut look = Vec2::ZERO;
+    let mut pan = Vec2::ZERO;
+    let mut pinch = 1.;
+
+    match active.as_slice() {
+        [finger] => {
+            look = finger.delta();
+        }
+        [first, second] => {
+            pan = (first.delta() + second.delta()) / 2.;
+
+            let previous = first.previous_position().distance(second.previous_position());
+            let current = first.position().distance(second.position());
+            if current > 0. && previous > 0. {
+                pinch = previous / current;
+            }
+        }
+        _ => return,
+    }
+
+    for (mut rig, state) in query.iter_mut() {
+        if !rig.mode.follows_pivot() {
+            continue;
+        }
+
+        if rig.mode != CameraMode::Isometric {
+            let sensitivity = settings.touch_look_sensitivity * rig.look_scale(&settings, &projections);
+            rig.yaw += look.x * sensitivity;
+            rig.pitch_by(-look.y * sensitivity);
+        }
+
+        let before = rig.target_distance;
+        rig.scale_zoom(pinch);
+        rig.limit_zoom_input(before);
+
+        if pan != Vec2::ZERO && rig.mode != CameraMode::FirstPerson {
+            let right = state.desired.rotation * Vec3::X;
+            let up = state.desired.rotation * Vec3::Y;
+            let scale = settings.touch_pan_sensitivity * rig.camera_distance;
+            rig.focus_offset += (-right * pan.x + up * pan.y) * scale;
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use bevy::{
+    prelude::*,
+    render::primitives::Aabb,
+    window::PrimaryWindow,
+};
+
+use crate::{
+    cursor::cursor_ray,
+    frame::FrameEntity,
+    input::{
+        MouseDeltas,
+        OrbitInertia,
+    },
+    input_map::{
+        ActionInput,
+        CameraAction,
+    },
+    mode::{
+        CameraMode,
+        CameraModeState,
+    },
+    player::{
+        Player,
+        rig_player,
+    },
+    rig::CameraRig,
+    settings::CameraSettings,
+    split_screen::{
+        InputSource,
+        accepts_keyboard_mouse,
+    },
+    target::CameraTarget,
+};
+
+#[derive(Component)]
+pub struct Turntable {
+    pub focus: Vec3,
+    pub focus_entity: Option<Entity>,
+    pub yaw: f32,
+    /// Elevation above the focus, in radians.
+    pub pitch: f32,
+    pub distance: f32,
+
+    pub min_distance: f32,
+    pub max_distance: f32,
+    pub rotate_sensitivity: f32,
+    pub pan_sensitivity: f32,
+    pub zoom_step: f32,
+}
+
+impl Default for Turntable {
+    fn default() -> Self {
+        Self {
+            focus: Vec3::ZERO,
+            focus_entity: None,
+            yaw: 0.,
+            pitch: 30f32.to_radians(),
+            distance: 10.,
+
+            min_distance: 0.5,
+            max_distance: 200.,
+            rotate_sensitivity: 0.005,
+            pan_sensitivity: 0.001,
+            zoom_step: 0.1,
+        }
+    }
+}
+
+impl Turntable {
+    pub fn camera_transform(&self) -> Transform {
+        let rotation = Quat::from_euler(EulerRot::YXZ, self.yaw, -self.pitch, 0.);
+        Transform::from_translation(self.focus + rotation * Vec3::Z * self.distance)
+            .looking_at(self.focus, Vec3::Y)
+    }
+}
+
+/// World-space bounding sphere of an entity's mesh bounds.
+pub fn bounding_sphere(aabb: &Aabb, transform: &GlobalTransform) -> (Vec3, f32) {
+    let center = transform.transform_point(aabb.center.into());
+    let radius = (Vec3::from(aabb.half_extents) * transform.compute_transform().scale.abs()).length();
+    (center, radius)
+}
+
+#[allow(clippy::type_complexity)]
+pub fn toggle_turntable(
+    mut commands: Commands,
+    actions: ActionInput,
+    players: Query<(&Player, Entity)>,
+    mut query: Query<(Entity, &mut CameraRig, &Transform, Option<&CameraTarget>, Option<&InputSource>)>,
+) {
+    for (pivot, mut rig, transform, target, source) in query.iter_mut() {
+        if !actions.just_pressed_by(CameraAction::ToggleTurntable, source) {
+            continue;
+        }
+        if rig.mode == CameraMode::Turntable {
+            rig.mode = CameraMode::Orbit;
+        } else {
+            // Frame whatever the pivot was following: its target, or else its player.
+            let focus_entity = target.map(|target| target.entity).or_else(|| rig_player(players.iter(), pivot));
+            commands.entity(pivot).insert(Turntable {
+                focus: transform.translation,
+                focus_entity,
+                yaw: -rig.yaw,
+                pitch: std::f32::consts::FRAC_PI_2 - rig.camera_pitch,
+                distance: rig.camera_distance,
+                ..default()
+            });
+            rig.mode = CameraMode::Turntable;
+        }
+    }
+}
+
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn update_turntable(
+    time: Res<Time>,
+    settings: Res<CameraSettings>,
+    actions: ActionInput,
+    deltas: Res<MouseDeltas>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut frames: EventWriter<FrameEntity>,
+    mut rigs: Query<(
+        Entity,
+        &CameraRig,
+        &mut Turntable,
+        &mut CameraModeState,
+        Option<&mut OrbitInertia>,
+        Option<&InputSource>,
+    )>,
+) {
+    let drag = deltas.motion;
+    let mut scroll = deltas.scroll;
+    if actions.scroll_modified() {
+        scroll = 0.;
+    }
+
+    for (pivot, rig, mut turntable, mut state, inertia, source) in rigs.iter_mut() {
+        if rig.mode != CameraMode::Turntable {
+            continue;
+        }
+
+        // Dragging and the wheel belong to the mouse.
+        let mouse = accepts_keyboard_mouse(source);
+        let drag = if mouse { drag } else { Vec2::ZERO };
+        let scroll = if mouse { scroll } else { 0. };
+
+        let mut orbit = if actions.pressed_by(CameraAction::OrbitModifier, source) {
+            drag * turntable.rotate_sensitivity
+        } else {
+            Vec2::ZERO
+        };
+        if let Some(mut inertia) = inertia {
+            orbit = inertia.step(orbit, time.delta_secs());
+        }
+        turntable.yaw -= orbit.x;
+        turntable.pitch = (turntable.pitch + orbit.y).clamp(-89f32.to_radians(), 89f32.to_radians());
+
+        if actions.pressed_by(CameraAction::PanModifier, source) {
+            let view = turntable.camera_transform();
+            let pan = (*view.left() * drag.x + *view.up() * drag.y) * turntable.pan_sensitivity * turntable.distance;
+            turntable.focus += pan;
+        }
+
+        if scroll != 0. {
+            let distance = (turntable.distance * (1. - turntable.zoom_step).powf(scroll))
+                .clamp(turntable.min_distance, turntable.max_distance);
+            if settings.zoom_to_cursor {
+                // Without a surface to hit, zoom about the cursor's point on the plane through the focus.
+                let view = turntable.camera_transform();
+                let camera = rig.camera_entity.and_then(|camera| cameras.get(camera).ok());
+                let hovered = windows.get_single().ok().zip(camera)
+                    .and_then(|(window, (camera, transform))| cursor_ray(window, camera, transform))
+                    .and_then(|ray| {
+                        let distance = ray.intersect_plane(turntable.focus, InfinitePlane3d::new(*view.back()))?;
+                        Some(ray.get_point(distance))
+                    });
+                if let Some(point) = hovered {
+                    turntable.focus = point + (turntable.focus - point) * (distance / turntable.distance);
+                }
+            }
+            turntable.distance = distance;
+        }
+
+        if actions.just_pressed_by(CameraAction::Frame, source) {
+            if let Some(entity) = turntable.focus_entity {
+                frames.send(FrameEntity { rig: pivot, entity });
+            }
+        }
+
+        state.desired = turntable.camera_transform();
+    }
+}
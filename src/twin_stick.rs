@@ -0,0 +1,141 @@
+use bevy::{
+    prelude::*,
+    window::PrimaryWindow,
+};
+
+use crate::{
+    input_map::{
+        ActionInput,
+        CameraAction,
+    },
+    mode::{
+        CameraMode,
+        CameraModeState,
+    },
+    player::{
+        Player,
+        rig_player,
+    },
+    rig::CameraRig,
+    split_screen::InputSource,
+};
+#[cfg(feature = "gamepad")]
+use crate::{
+    settings::CameraSettings,
+    split_screen::accepts_gamepad,
+};
+
+#[derive(Component)]
+pub struct TwinStick {
+    pub height: f32,
+    /// Angle of the view above the horizon.
+    pub tilt: f32,
+    /// Furthest the framing shifts toward the aim direction.
+    pub aim_bias: f32,
+    /// Cursor distance from the player that gives the full `aim_bias`.
+    pub aim_range: f32,
+    /// How quickly the framing follows aim changes, per second.
+    pub aim_stiffness: f32,
+    /// Aim from code or a right stick; overrides the cursor while non-zero.
+    pub stick_aim: Vec2,
+    pub aim_offset: Vec3,
+}
+
+impl Default for TwinStick {
+    fn default() -> Self {
+        Self {
+            height: 14.,
+            tilt: 70f32.to_radians(),
+            aim_bias: 4.,
+            aim_range: 8.,
+            aim_stiffness: 5.,
+            stick_aim: Vec2::ZERO,
+            aim_offset: Vec3::ZERO,
+        }
+    }
+}
+
+impl TwinStick {
+    pub fn camera_transform(&self, target: Vec3) -> Transform {
+        let focus = target + self.aim_offset;
+        let back = self.height / self.tilt.tan();
+        Transform::from_translation(focus + Vec3::new(0., self.height, back)).looking_at(focus, Vec3::Y)
+    }
+
+    /// Framing offset for an aim vector on the ground plane, scaled by its length.
+    pub fn aim_target(&self, aim: Vec3) -> Vec3 {
+        let strength = (aim.length() / self.aim_range.max(f32::EPSILON)).min(1.);
+        aim.normalize_or_zero() * strength * self.aim_bias
+    }
+}
+
+pub fn toggle_twin_stick(
+    mut commands: Commands,
+    actions: ActionInput,
+    mut query: Query<(Entity, &mut CameraRig, Option<&InputSource>)>,
+) {
+    for (pivot, mut rig, source) in query.iter_mut() {
+        if !actions.just_pressed_by(CameraAction::ToggleTwinStick, source) {
+            continue;
+        }
+        if rig.mode == CameraMode::TwinStick {
+            rig.mode = CameraMode::Orbit;
+        } else {
+            commands.entity(pivot).insert(TwinStick::default());
+            rig.mode = CameraMode::TwinStick;
+        }
+    }
+}
+
+#[cfg(feature = "gamepad")]
+pub fn read_aim_stick(
+    settings: Res<CameraSettings>,
+    gamepads: Query<(Entity, &Gamepad)>,
+    mut rigs: Query<(&mut TwinStick, Option<&InputSource>)>,
+) {
+    for (mut twin_stick, source) in rigs.iter_mut() {
+        twin_stick.stick_aim = gamepads
+            .iter()
+            .filter(|(entity, _)| accepts_gamepad(source, *entity))
+            .map(|(_, gamepad)| settings.right_stick.apply(gamepad.right_stick()))
+            .find(|stick| *stick != Vec2::ZERO)
+            .unwrap_or(Vec2::ZERO);
+    }
+}
+
+pub fn update_twin_stick(
+    time: Res<Time>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    players: Query<(&Player, &Transform), Without<CameraRig>>,
+    mut rigs: Query<(Entity, &CameraRig, &mut TwinStick, &mut CameraModeState, &Transform)>,
+) {
+    let cursor = windows.get_single().ok().and_then(|window| window.cursor_position());
+
+    for (entity, rig, mut twin_stick, mut state, pivot) in rigs.iter_mut() {
+        if rig.mode != CameraMode::TwinStick {
+            continue;
+        }
+        let player = rig_player(players.iter(), entity).map(|transform| transform.translation);
+        let target = player.unwrap_or(pivot.translation);
+
+        let aim = if twin_stick.stick_aim != Vec2::ZERO {
+            Vec3::new(twin_stick.stick_aim.x, 0., -twin_stick.stick_aim.y) * twin_stick.aim_range
+        } else {
+            cursor
+                .zip(rig.camera_entity.and_then(|camera| cameras.get(camera).ok()))
+                .and_then(|(cursor, (camera, camera_transform))| camera.viewport_to_world(camera_transform, cursor).ok())
+                .and_then(|ray| {
+                    let distance = ray.intersect_plane(target, InfinitePlane3d::new(Vec3::Y))?;
+                    Some(ray.get_point(distance) - target)
+                })
+                .unwrap_or(Vec3::ZERO)
+        };
+
+        let aim_target = twin_stick.aim_target(aim.with_y(0.));
+        let blend = 1. - (-twin_stick.aim_stiffness * time.delta_secs()).exp();
+        twin_stick.aim_offset = twin_stick.aim_offset.lerp(aim_target, blend);
+
+        state.desired = twin_stick.camera_transform(target);
+    }
+}
@@ -0,0 +1,168 @@
+use bevy::prelude::*;
+
+use crate::easing::Easing;
+
+/// A candidate framing; the highest-priority one drives each `CameraBrain`.
+#[derive(Component)]
+#[require(Transform)]
+pub struct VirtualCamera {
+    pub priority: i32,
+    /// Entity whose position, plus `offset`, the camera sits at.
+    pub follow: Option<Entity>,
+    /// Entity the camera keeps aimed at; otherwise its own rotation is used.
+    pub look_at: Option<Entity>,
+    pub offset: Vec3,
+    pub fov: Option<f32>,
+    /// Time constant of the follow, in seconds; 0 follows rigidly.
+    pub damping: f32,
+    /// Framing computed this frame, read by the brains.
+    pub pose: Option<Transform>,
+}
+
+impl Default for VirtualCamera {
+    fn default() -> Self {
+        Self {
+            priority: 0,
+            follow: None,
+            look_at: None,
+            offset: Vec3::ZERO,
+            fov: None,
+            damping: 0.,
+
+            pose: None,
+        }
+    }
+}
+
+/// Put on a real camera to have it driven by the active virtual camera.
+#[derive(Component)]
+#[require(Camera3d)]
+pub struct CameraBrain {
+    pub blend_duration: f32,
+    pub easing: Easing,
+
+    active: Option<Entity>,
+    from: Option<(Transform, f32)>,
+    elapsed: f32,
+}
+
+impl Default for CameraBrain {
+    fn default() -> Self {
+        Self {
+            blend_duration: 1.,
+            easing: Easing::EaseInOutCubic,
+
+            active: None,
+            from: None,
+            elapsed: 0.,
+        }
+    }
+}
+
+impl CameraBrain {
+    pub fn active(&self) -> Option<Entity> {
+        self.active
+    }
+
+    pub fn is_blending(&self) -> bool {
+        self.from.is_some()
+    }
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct VirtualCameraChanged {
+    pub brain: Entity,
+    pub from: Option<Entity>,
+    pub to: Option<Entity>,
+}
+
+pub fn update_virtual_cameras(
+    time: Res<Time>,
+    targets: Query<&GlobalTransform>,
+    mut vcams: Query<(&mut VirtualCamera, &GlobalTransform)>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for (mut vcam, transform) in vcams.iter_mut() {
+        let own = transform.compute_transform();
+
+        let goal = vcam.follow
+            .and_then(|follow| targets.get(follow).ok())
+            .map(|follow| follow.translation() + vcam.offset)
+            .unwrap_or(own.translation);
+        let translation = match vcam.pose {
+            Some(pose) if vcam.damping > 0. => pose.translation.lerp(goal, 1. - (-delta_seconds / vcam.damping).exp()),
+            _ => goal,
+        };
+
+        let mut pose = Transform::from_translation(translation).with_rotation(own.rotation);
+        if let Some(look_at) = vcam.look_at.and_then(|look_at| targets.get(look_at).ok()) {
+            pose.look_at(look_at.translation(), Vec3::Y);
+        }
+        vcam.pose = Some(pose);
+    }
+}
+
+pub fn update_camera_brains(
+    time: Res<Time>,
+    mut events: EventWriter<VirtualCameraChanged>,
+    vcams: Query<(Entity, &VirtualCamera)>,
+    mut brains: Query<(Entity, &mut CameraBrain, &mut Transform, &mut Projection)>,
+) {
+    let best = vcams
+        .iter()
+        .filter(|(_, vcam)| vcam.pose.is_some())
+        .max_by_key(|(_, vcam)| vcam.priority);
+
+    for (entity, mut brain, mut transform, mut projection) in brains.iter_mut() {
+        let Some((active, vcam)) = best else {
+            continue;
+        };
+        let Some(pose) = vcam.pose else {
+            continue;
+        };
+
+        let Projection::Perspective(perspective) = projection.as_mut() else {
+            continue;
+        };
+
+        if brain.active != Some(active) {
+            events.send(VirtualCameraChanged {
+                brain: entity,
+                from: brain.active,
+                to: Some(active),
+            });
+            // The first vcam cuts in; later changes blend from where the camera is.
+            if brain.active.is_some() {
+                brain.from = Some((*transform, perspective.fov));
+                brain.elapsed = 0.;
+            }
+            brain.active = Some(active);
+        }
+
+        let fov = vcam.fov.unwrap_or(perspective.fov);
+
+        match brain.from {
+            Some((from, from_fov)) => {
+                brain.elapsed += time.delta_secs();
+                let t = if brain.blend_duration > 0. {
+                    brain.easing.apply(brain.elapsed / brain.blend_duration)
+                } else {
+                    1.
+                };
+
+                transform.translation = from.translation.lerp(pose.translation, t);
+                transform.rotation = from.rotation.slerp(pose.rotation, t);
+                perspective.fov = from_fov.lerp(fov, t);
+
+                if t >= 1. {
+                    brain.from = None;
+                }
+            }
+            None => {
+                *transform = pose;
+                perspective.fov = fov;
+            }
+        }
+    }
+}